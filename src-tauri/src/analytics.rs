@@ -0,0 +1,397 @@
+//! Drop-rate analytics over run records.
+//!
+//! Runs are grouped by scene and Terror Zone flag. For every item and rarity
+//! class that dropped in a group, the per-run drop probability is reported
+//! with a 95% Wilson score interval, and two groups can be compared with a
+//! two-proportion z-test.
+
+use crate::custom_items::Qualities;
+use crate::items::{self, RarityClass};
+use crate::models::RunRecord;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+
+/// z-score of the two-sided 95% confidence level.
+const Z_95: f64 = 1.959_963_984_540_054;
+
+/// Significance level used by `compare`.
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// What a drop rate is measured for.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(tag = "kind", content = "value", rename_all = "lowercase")]
+pub enum DropTarget {
+    /// A single item ID.
+    Item(String),
+    /// Any item of a rarity class.
+    Rarity(RarityClass),
+}
+
+impl DropTarget {
+    /// Returns the number of matching drops in a run.
+    fn count_in(&self, run: &RunRecord, qualities: &Qualities) -> usize {
+        run.drops
+            .iter()
+            .filter(|d| match self {
+                DropTarget::Item(id) => *d == id,
+                DropTarget::Rarity(rarity) => items::rarity_of(d, qualities) == Some(*rarity),
+            })
+            .count()
+    }
+}
+
+/// Selects the runs of one scene, optionally restricted to TZ or non-TZ runs.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SceneGroup {
+    /// Scene identifier.
+    pub scene_id: String,
+    /// Terror Zone flag; `None` includes both.
+    #[serde(default)]
+    pub is_tz: Option<bool>,
+}
+
+impl SceneGroup {
+    fn contains(&self, run: &RunRecord) -> bool {
+        run.scene_id == self.scene_id && !matches!(self.is_tz, Some(tz) if run.is_tz != tz)
+    }
+}
+
+/// Drop rate of one target within a group of runs.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct DropRate {
+    /// The item or rarity class measured.
+    pub target: DropTarget,
+    /// Total number of matching drops.
+    pub drops: usize,
+    /// Number of runs with at least one matching drop.
+    pub runs_with_drop: usize,
+    /// Average matching drops per run.
+    pub drops_per_run: f64,
+    /// Average runs per matching drop; `None` if it never dropped.
+    pub runs_per_drop: Option<f64>,
+    /// Lower bound of the 95% Wilson interval for the chance a run drops it.
+    pub ci_low: f64,
+    /// Upper bound of the 95% Wilson interval for the chance a run drops it.
+    pub ci_high: f64,
+}
+
+impl DropRate {
+    /// Measures a target over a set of runs.
+    pub fn measure(target: DropTarget, runs: &[&RunRecord], qualities: &Qualities) -> Self {
+        let mut drops = 0;
+        let mut runs_with_drop = 0;
+        for run in runs {
+            let n = target.count_in(run, qualities);
+            drops += n;
+            runs_with_drop += usize::from(n > 0);
+        }
+        Self::from_counts(target, drops, runs_with_drop, runs.len())
+    }
+
+    fn from_counts(target: DropTarget, drops: usize, runs_with_drop: usize, runs: usize) -> Self {
+        let (ci_low, ci_high) = wilson_interval(runs_with_drop, runs, Z_95);
+        Self {
+            target,
+            drops,
+            runs_with_drop,
+            drops_per_run: if runs == 0 {
+                0.0
+            } else {
+                drops as f64 / runs as f64
+            },
+            runs_per_drop: (drops > 0).then(|| runs as f64 / drops as f64),
+            ci_low,
+            ci_high,
+        }
+    }
+}
+
+/// Drop rates for one scene and TZ combination.
+#[derive(Serialize, Debug)]
+pub struct DropRateGroup {
+    /// Scene identifier.
+    pub scene_id: String,
+    /// Whether these are Terror Zone runs.
+    pub is_tz: bool,
+    /// Number of runs in the group.
+    pub runs: usize,
+    /// Rates per item that dropped at least once, most frequent first.
+    pub items: Vec<DropRate>,
+    /// Rates per rarity class that dropped at least once.
+    pub rarities: Vec<DropRate>,
+}
+
+/// Result of comparing one drop target between two groups.
+#[derive(Serialize, Debug)]
+pub struct DropRateComparison {
+    /// Number of runs in the first group.
+    pub a_runs: usize,
+    /// Drop rate in the first group.
+    pub a: DropRate,
+    /// Number of runs in the second group.
+    pub b_runs: usize,
+    /// Drop rate in the second group.
+    pub b: DropRate,
+    /// Difference in the chance a run drops the target (a minus b).
+    pub difference: f64,
+    /// Two-proportion z statistic.
+    pub z: f64,
+    /// Two-sided p-value.
+    pub p_value: f64,
+    /// Whether the difference is significant at `SIGNIFICANCE_LEVEL`.
+    pub significant: bool,
+}
+
+/// Computes drop rates for every scene and TZ combination.
+///
+/// # Arguments
+/// * `runs` - The runs to measure.
+/// * `qualities` - Custom item qualities, for the rarity rates.
+///
+/// # Returns
+/// Groups ordered by scene ID, non-TZ before TZ.
+pub fn drop_rates(runs: &[&RunRecord], qualities: &Qualities) -> Vec<DropRateGroup> {
+    let mut groups: BTreeMap<(&str, bool), Vec<&RunRecord>> = BTreeMap::new();
+    for &run in runs {
+        groups
+            .entry((run.scene_id.as_str(), run.is_tz))
+            .or_default()
+            .push(run);
+    }
+
+    groups
+        .into_iter()
+        .map(|((scene_id, is_tz), runs)| {
+            let mut targets: BTreeMap<DropTarget, (usize, HashSet<usize>)> = BTreeMap::new();
+            for (i, run) in runs.iter().enumerate() {
+                for drop in &run.drops {
+                    let mut record = |target| {
+                        let (drops, hit_runs) = targets.entry(target).or_default();
+                        *drops += 1;
+                        hit_runs.insert(i);
+                    };
+                    record(DropTarget::Item(drop.clone()));
+                    if let Some(rarity) = items::rarity_of(drop, qualities) {
+                        record(DropTarget::Rarity(rarity));
+                    }
+                }
+            }
+
+            let (mut items, rarities): (Vec<DropRate>, Vec<DropRate>) = targets
+                .into_iter()
+                .map(|(target, (drops, hit_runs))| {
+                    DropRate::from_counts(target, drops, hit_runs.len(), runs.len())
+                })
+                .partition(|rate| matches!(rate.target, DropTarget::Item(_)));
+            items.sort_by_key(|rate| Reverse(rate.drops));
+
+            DropRateGroup {
+                scene_id: scene_id.to_string(),
+                is_tz,
+                runs: runs.len(),
+                items,
+                rarities,
+            }
+        })
+        .collect()
+}
+
+/// Compares the chance a run drops `target` between two groups of runs.
+pub fn compare(
+    runs: &[&RunRecord],
+    a: &SceneGroup,
+    b: &SceneGroup,
+    target: DropTarget,
+    qualities: &Qualities,
+) -> DropRateComparison {
+    let a_runs: Vec<&RunRecord> = runs.iter().copied().filter(|r| a.contains(r)).collect();
+    let b_runs: Vec<&RunRecord> = runs.iter().copied().filter(|r| b.contains(r)).collect();
+    let a_rate = DropRate::measure(target.clone(), &a_runs, qualities);
+    let b_rate = DropRate::measure(target, &b_runs, qualities);
+
+    let (n1, n2) = (a_runs.len() as f64, b_runs.len() as f64);
+    let p1 = if n1 > 0.0 {
+        a_rate.runs_with_drop as f64 / n1
+    } else {
+        0.0
+    };
+    let p2 = if n2 > 0.0 {
+        b_rate.runs_with_drop as f64 / n2
+    } else {
+        0.0
+    };
+    let z = two_proportion_z(
+        a_rate.runs_with_drop,
+        a_runs.len(),
+        b_rate.runs_with_drop,
+        b_runs.len(),
+    );
+    let p_value = 2.0 * (1.0 - normal_cdf(z.abs()));
+
+    DropRateComparison {
+        a_runs: a_runs.len(),
+        a: a_rate,
+        b_runs: b_runs.len(),
+        b: b_rate,
+        difference: p1 - p2,
+        z,
+        p_value,
+        significant: p_value < SIGNIFICANCE_LEVEL,
+    }
+}
+
+/// Wilson score interval for `successes` out of `trials`.
+///
+/// Unlike the normal approximation, this stays within [0, 1] and gives a
+/// useful upper bound when nothing has dropped yet.
+fn wilson_interval(successes: usize, trials: usize, z: f64) -> (f64, f64) {
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z2 = z * z;
+    let denom = 1.0 + z2 / n;
+    let center = (p + z2 / (2.0 * n)) / denom;
+    let half = z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denom;
+    ((center - half).max(0.0), (center + half).min(1.0))
+}
+
+/// Pooled two-proportion z statistic; 0 when either group is empty or the
+/// pooled proportion is 0 or 1.
+fn two_proportion_z(x1: usize, n1: usize, x2: usize, n2: usize) -> f64 {
+    if n1 == 0 || n2 == 0 {
+        return 0.0;
+    }
+    let (n1, n2) = (n1 as f64, n2 as f64);
+    let pooled = (x1 + x2) as f64 / (n1 + n2);
+    let se = (pooled * (1.0 - pooled) * (1.0 / n1 + 1.0 / n2)).sqrt();
+    if se == 0.0 {
+        return 0.0;
+    }
+    (x1 as f64 / n1 - x2 as f64 / n2) / se
+}
+
+/// Standard normal cumulative distribution function.
+fn normal_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+/// Error function (Abramowitz and Stegun 7.1.26, absolute error < 1.5e-7).
+fn erf(x: f64) -> f64 {
+    let sign = x.signum();
+    let x = x.abs();
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    sign * (1.0 - poly * (-x * x).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_items::CustomQuality;
+    use crate::models::test_run;
+
+    fn run(scene: &str, drops: &[&str], is_tz: bool) -> RunRecord {
+        test_run(scene, "2024-06-01", 60_000, drops, is_tz)
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn wilson_interval_bounds() {
+        let (low, high) = wilson_interval(0, 10, Z_95);
+        assert_eq!(low, 0.0);
+        assert!(close(high, 0.2775));
+
+        let (low, high) = wilson_interval(5, 10, Z_95);
+        assert!(close(low, 0.2366));
+        assert!(close(high, 0.7634));
+
+        assert_eq!(wilson_interval(0, 0, Z_95), (0.0, 1.0));
+    }
+
+    #[test]
+    fn normal_cdf_matches_table() {
+        assert!(close(normal_cdf(0.0), 0.5));
+        assert!(close(normal_cdf(Z_95), 0.975));
+        assert!(close(normal_cdf(-1.0), 0.1587));
+    }
+
+    #[test]
+    fn groups_by_scene_and_tz() {
+        let runs = [
+            run("pit", &["r30", "r30"], false),
+            run("pit", &[], false),
+            run("pit", &["r30"], true),
+            run("countess", &["custom:Jewel:2", "custom-1"], false),
+        ];
+        let refs: Vec<&RunRecord> = runs.iter().collect();
+        let qualities = Qualities::from([("custom-1".to_string(), CustomQuality::Rare)]);
+        let groups = drop_rates(&refs, &qualities);
+
+        assert_eq!(groups.len(), 3);
+        let pit = &groups[1];
+        assert_eq!(
+            (pit.scene_id.as_str(), pit.is_tz, pit.runs),
+            ("pit", false, 2)
+        );
+        let ber = &pit.items[0];
+        assert_eq!(ber.target, DropTarget::Item("r30".to_string()));
+        assert_eq!((ber.drops, ber.runs_with_drop), (2, 1));
+        assert_eq!(ber.drops_per_run, 1.0);
+        assert_eq!(ber.runs_per_drop, Some(1.0));
+        assert_eq!(
+            pit.rarities[0].target,
+            DropTarget::Rarity(RarityClass::Rune)
+        );
+        let countess: Vec<&DropTarget> = groups[0].rarities.iter().map(|r| &r.target).collect();
+        assert_eq!(
+            countess,
+            [
+                &DropTarget::Rarity(RarityClass::Rare),
+                &DropTarget::Rarity(RarityClass::Magic)
+            ]
+        );
+    }
+
+    #[test]
+    fn compares_two_scenes() {
+        let mut runs = Vec::new();
+        runs.extend((0..100).map(|i| run("countess", if i < 30 { &["r30"] } else { &[] }, false)));
+        runs.extend((0..100).map(|i| run("pit", if i < 10 { &["r30"] } else { &[] }, false)));
+        let refs: Vec<&RunRecord> = runs.iter().collect();
+        let group = |scene: &str| SceneGroup {
+            scene_id: scene.to_string(),
+            is_tz: None,
+        };
+
+        let result = compare(
+            &refs,
+            &group("countess"),
+            &group("pit"),
+            DropTarget::Rarity(RarityClass::Rune),
+            &Qualities::new(),
+        );
+        assert_eq!((result.a_runs, result.b_runs), (100, 100));
+        assert!(close(result.difference, 0.2));
+        assert!(close(result.z, 3.5355));
+        assert!(result.significant);
+
+        let same = compare(
+            &refs,
+            &group("pit"),
+            &group("pit"),
+            DropTarget::Item("r30".to_string()),
+            &Qualities::new(),
+        );
+        assert!(close(same.p_value, 1.0));
+        assert!(!same.significant);
+    }
+}
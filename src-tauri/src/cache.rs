@@ -0,0 +1,468 @@
+//! In-memory index of local run records.
+//!
+//! The cache is loaded from the database once at startup and kept in sync by
+//! writing every mutation through to the database first. Queries such as
+//! `get_runs` are then answered from memory without touching disk.
+
+use crate::custom_items::Qualities;
+use crate::db::Database;
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    HistoryFilter, RunPage, RunPatch, RunQuery, RunRecord, RunSortKey, Session, SortDirection,
+};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// Runs in insertion order plus a lookup table from ID to position.
+#[derive(Default)]
+struct RunIndex {
+    runs: Vec<RunRecord>,
+    by_id: HashMap<String, usize>,
+}
+
+impl RunIndex {
+    /// Builds an index from runs in insertion order.
+    fn new(runs: Vec<RunRecord>) -> Self {
+        let mut index = Self {
+            runs,
+            by_id: HashMap::new(),
+        };
+        index.reindex();
+        index
+    }
+
+    /// Rebuilds the ID lookup table after positions changed.
+    fn reindex(&mut self) {
+        self.by_id = self
+            .runs
+            .iter()
+            .enumerate()
+            .map(|(i, r)| (r.id.clone(), i))
+            .collect();
+    }
+
+    /// Removes a run by ID.
+    ///
+    /// # Returns
+    /// `true` if a run was removed.
+    fn remove(&mut self, id: &str) -> bool {
+        match self.by_id.get(id) {
+            Some(&idx) => {
+                self.runs.remove(idx);
+                self.reindex();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes several runs by ID, reindexing once.
+    fn remove_many(&mut self, ids: &[&str]) {
+        let ids: HashSet<&str> = ids.iter().copied().collect();
+        self.runs.retain(|r| !ids.contains(r.id.as_str()));
+        self.reindex();
+    }
+
+    /// Appends a run, replacing any existing run with the same ID.
+    ///
+    /// A replaced run moves to the end, matching the database's row order.
+    fn insert(&mut self, run: RunRecord) {
+        self.remove(&run.id);
+        self.by_id.insert(run.id.clone(), self.runs.len());
+        self.runs.push(run);
+    }
+}
+
+/// Synchronized in-memory copy of the local runs table.
+pub struct RunCache {
+    index: RwLock<RunIndex>,
+}
+
+impl RunCache {
+    /// Loads every local run from the database.
+    pub fn load(db: &Database) -> AppResult<Self> {
+        Ok(Self {
+            index: RwLock::new(RunIndex::new(db.load_runs()?)),
+        })
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, RunIndex> {
+        self.index.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, RunIndex> {
+        self.index.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns one sorted page of the runs matching the filter.
+    ///
+    /// Only the runs on the requested page are cloned; ties are broken by
+    /// timestamp and ID so consecutive pages never overlap.
+    ///
+    /// # Arguments
+    /// * `filter` - Optional filter criteria.
+    /// * `qualities` - Custom item qualities, for the filter's rarity criterion.
+    /// * `query` - Sort key, direction, offset and limit.
+    pub fn query(
+        &self,
+        filter: Option<&HistoryFilter>,
+        qualities: &Qualities,
+        query: &RunQuery,
+    ) -> RunPage {
+        let index = self.read();
+        let mut matched: Vec<&RunRecord> = match filter {
+            Some(f) => index
+                .runs
+                .iter()
+                .filter(|r| f.matches(r, qualities))
+                .collect(),
+            None => index.runs.iter().collect(),
+        };
+        let key = query.sortBy.unwrap_or_default();
+        let desc = query.sortDir.unwrap_or_default() == SortDirection::Desc;
+        matched.sort_unstable_by(|a, b| {
+            let ord = match key {
+                RunSortKey::Timestamp => Ordering::Equal,
+                RunSortKey::Duration => a.duration_ms.cmp(&b.duration_ms),
+                RunSortKey::Scene => a.scene_id.cmp(&b.scene_id),
+                RunSortKey::Drops => a.drops.len().cmp(&b.drops.len()),
+            }
+            .then_with(|| a.timestamp.cmp(&b.timestamp))
+            .then_with(|| a.id.cmp(&b.id));
+            if desc {
+                ord.reverse()
+            } else {
+                ord
+            }
+        });
+        let total = matched.len();
+        let runs = matched
+            .into_iter()
+            .skip(query.offset.unwrap_or(0))
+            .take(query.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+        RunPage { runs, total }
+    }
+
+    /// Runs a computation over the runs matching the filter, in insertion order.
+    ///
+    /// The read lock is held for the duration of `f`, so nothing is cloned.
+    pub fn with_matching<T>(
+        &self,
+        filter: Option<&HistoryFilter>,
+        qualities: &Qualities,
+        f: impl FnOnce(&[&RunRecord]) -> T,
+    ) -> T {
+        let index = self.read();
+        let matched: Vec<&RunRecord> = index
+            .runs
+            .iter()
+            .filter(|r| !matches!(filter, Some(f) if !f.matches(r, qualities)))
+            .collect();
+        f(&matched)
+    }
+
+    /// Runs a computation on one run.
+    ///
+    /// # Returns
+    /// The result of `f`, or [`AppError::NotFound`] if no run has this ID.
+    pub fn with_run<T>(&self, id: &str, f: impl FnOnce(&RunRecord) -> T) -> AppResult<T> {
+        let index = self.read();
+        let idx = *index
+            .by_id
+            .get(id)
+            .ok_or_else(|| AppError::NotFound(format!("run {}", id)))?;
+        Ok(f(&index.runs[idx]))
+    }
+
+    /// Saves a run to the database, then to the cache.
+    ///
+    /// The write lock is held across both steps, so readers never observe
+    /// the cache and database out of sync.
+    pub fn insert(&self, db: &Database, run: RunRecord) -> AppResult<()> {
+        let mut index = self.write();
+        db.insert_run(&run)?;
+        index.insert(run);
+        Ok(())
+    }
+
+    /// Applies a patch to a saved run and writes it through.
+    ///
+    /// # Arguments
+    /// * `db` - The run database.
+    /// * `id` - The ID of the run to change.
+    /// * `patch` - The fields to change; already validated by the caller.
+    ///
+    /// # Returns
+    /// The updated run, or [`AppError::NotFound`] if no run has this ID.
+    pub fn update(&self, db: &Database, id: &str, patch: &RunPatch) -> AppResult<RunRecord> {
+        let mut index = self.write();
+        let idx = *index
+            .by_id
+            .get(id)
+            .ok_or_else(|| AppError::NotFound(format!("run {}", id)))?;
+
+        let mut run = index.runs[idx].clone();
+        if run.apply_patch(patch, chrono::Utc::now().timestamp_millis()) {
+            db.update_runs(std::slice::from_ref(&run))?;
+            index.runs[idx] = run.clone();
+        }
+        Ok(run)
+    }
+
+    /// Applies the same patch to every run matching a predicate, in one transaction.
+    ///
+    /// # Arguments
+    /// * `db` - The run database.
+    /// * `selector` - Chooses the runs to change.
+    /// * `patch` - The fields to change; already validated by the caller.
+    ///
+    /// # Returns
+    /// The number of runs that changed.
+    pub fn update_where(
+        &self,
+        db: &Database,
+        selector: impl Fn(&RunRecord) -> bool,
+        patch: &RunPatch,
+    ) -> AppResult<usize> {
+        let mut index = self.write();
+        let edited_at = chrono::Utc::now().timestamp_millis();
+
+        let changed: Vec<(usize, RunRecord)> = index
+            .runs
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| selector(r))
+            .filter_map(|(i, r)| {
+                let mut run = r.clone();
+                run.apply_patch(patch, edited_at).then_some((i, run))
+            })
+            .collect();
+
+        let runs: Vec<RunRecord> = changed.iter().map(|(_, r)| r.clone()).collect();
+        db.update_runs(&runs)?;
+        for (i, run) in changed.iter() {
+            index.runs[*i] = run.clone();
+        }
+        Ok(changed.len())
+    }
+
+    /// Moves runs to the trash in one transaction, then drops them from the cache.
+    ///
+    /// # Returns
+    /// The number of runs deleted.
+    pub fn delete(&self, db: &Database, ids: &[&str]) -> AppResult<usize> {
+        let mut index = self.write();
+        let deleted = db.trash_runs(ids, chrono::Utc::now().timestamp_millis())?;
+        index.remove_many(ids);
+        Ok(deleted)
+    }
+
+    /// Moves all runs to the trash, then empties the cache.
+    pub fn clear(&self, db: &Database) -> AppResult<()> {
+        let mut index = self.write();
+        db.trash_all_runs(chrono::Utc::now().timestamp_millis())?;
+        *index = RunIndex::default();
+        Ok(())
+    }
+
+    /// Deletes a session and moves its runs to the trash, then drops them from the cache.
+    ///
+    /// # Returns
+    /// The number of runs deleted.
+    pub fn delete_session(&self, db: &Database, id: &str) -> AppResult<usize> {
+        let mut index = self.write();
+        let deleted = db.delete_session(id, chrono::Utc::now().timestamp_millis())?;
+        index.runs.retain(|r| r.session_id.as_deref() != Some(id));
+        index.reindex();
+        Ok(deleted)
+    }
+
+    /// Moves every run of one session into another, then updates the cache.
+    ///
+    /// # Arguments
+    /// * `db` - The run database.
+    /// * `source_id` - The session to merge away.
+    /// * `target` - The merged session, already combined by the caller.
+    ///
+    /// # Returns
+    /// The number of runs moved.
+    pub fn merge_sessions(
+        &self,
+        db: &Database,
+        source_id: &str,
+        target: &Session,
+    ) -> AppResult<usize> {
+        let mut index = self.write();
+        let moved = db.merge_sessions(source_id, target)?;
+        for run in index
+            .runs
+            .iter_mut()
+            .filter(|r| r.session_id.as_deref() == Some(source_id))
+        {
+            run.session_id = Some(target.id.clone());
+        }
+        Ok(moved)
+    }
+
+    /// Merges one custom item into another, then updates the cache.
+    ///
+    /// # Arguments
+    /// * `db` - The run database.
+    /// * `source_id` - The custom item to merge away.
+    /// * `target_id` - The custom item to keep.
+    ///
+    /// # Returns
+    /// The number of runs changed.
+    pub fn merge_custom_items(
+        &self,
+        db: &Database,
+        source_id: &str,
+        target_id: &str,
+    ) -> AppResult<usize> {
+        let mut index = self.write();
+        let changed = db.merge_custom_items(source_id, target_id)?;
+        for run in index.runs.iter_mut() {
+            run.replace_drop_id(source_id, target_id);
+        }
+        Ok(changed)
+    }
+
+    /// Restores a run from the trash and adds it back to the cache.
+    ///
+    /// # Returns
+    /// The restored run, or [`AppError::NotFound`] if it is not in the trash.
+    pub fn restore(&self, db: &Database, id: &str) -> AppResult<RunRecord> {
+        let mut index = self.write();
+        let run = db
+            .restore_run(id)?
+            .ok_or_else(|| AppError::NotFound(format!("run {} in trash", id)))?;
+        index.insert(run.clone());
+        Ok(run)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::test_run;
+
+    /// Checks that the cache holds exactly the runs of the database, in order.
+    fn assert_in_sync(cache: &RunCache, db: &Database) {
+        let cached = serde_json::to_value(&cache.read().runs).unwrap();
+        let stored = serde_json::to_value(db.load_runs().unwrap()).unwrap();
+        assert_eq!(cached, stored);
+        let index = cache.read();
+        for (i, run) in index.runs.iter().enumerate() {
+            assert_eq!(index.by_id[&run.id], i);
+        }
+        assert_eq!(index.by_id.len(), index.runs.len());
+    }
+
+    /// A database and cache holding runs `a` to `e`, timestamps 1 to 5.
+    fn setup() -> (Database, RunCache) {
+        let db = Database::open_in_memory().unwrap();
+        let cache = RunCache::load(&db).unwrap();
+        for (i, id) in ["a", "b", "c", "d", "e"].into_iter().enumerate() {
+            let mut run = test_run("pit", "2024-06-01", 60_000, &[], false);
+            run.id = id.to_string();
+            run.timestamp = i as i64 + 1;
+            cache.insert(&db, run).unwrap();
+        }
+        assert_in_sync(&cache, &db);
+        (db, cache)
+    }
+
+    fn ids(page: &RunPage) -> Vec<&str> {
+        page.runs.iter().map(|r| r.id.as_str()).collect()
+    }
+
+    #[test]
+    fn pages_break_ties_by_timestamp_and_id() {
+        let (db, cache) = setup();
+        // Equal durations everywhere, and two runs sharing a timestamp
+        let mut run = test_run("pit", "2024-06-01", 60_000, &[], false);
+        run.id = "bb".to_string();
+        run.timestamp = 2;
+        cache.insert(&db, run).unwrap();
+
+        let page = |offset, dir| {
+            cache.query(
+                None,
+                &Qualities::new(),
+                &RunQuery {
+                    sortBy: Some(RunSortKey::Duration),
+                    sortDir: Some(dir),
+                    offset: Some(offset),
+                    limit: Some(2),
+                },
+            )
+        };
+        let mut asc = Vec::new();
+        for offset in [0, 2, 4] {
+            asc.extend(
+                page(offset, SortDirection::Asc)
+                    .runs
+                    .into_iter()
+                    .map(|r| r.id),
+            );
+        }
+        assert_eq!(asc, ["a", "b", "bb", "c", "d", "e"]);
+        let first = page(0, SortDirection::Desc);
+        assert_eq!(ids(&first), ["e", "d"]);
+        assert_eq!(first.total, 6);
+        assert_eq!(ids(&page(2, SortDirection::Desc)), ["c", "bb"]);
+    }
+
+    #[test]
+    fn mutations_keep_the_cache_equal_to_the_database() {
+        let (db, cache) = setup();
+
+        let patch = RunPatch {
+            drops: Some(vec!["custom-old".to_string()]),
+            ..RunPatch::default()
+        };
+        cache.update(&db, "b", &patch).unwrap();
+        assert_in_sync(&cache, &db);
+        assert!(cache.update(&db, "missing", &patch).is_err());
+
+        let retag = RunPatch {
+            scene_id: Some("chaos".to_string()),
+            ..RunPatch::default()
+        };
+        let changed = cache
+            .update_where(&db, |r| r.timestamp >= 3, &retag)
+            .unwrap();
+        assert_eq!(changed, 3);
+        assert_in_sync(&cache, &db);
+        // Runs already in the scene are left alone
+        assert_eq!(cache.update_where(&db, |_| true, &retag).unwrap(), 2);
+
+        assert_eq!(
+            cache
+                .merge_custom_items(&db, "custom-old", "custom-new")
+                .unwrap(),
+            1
+        );
+        assert_in_sync(&cache, &db);
+        cache
+            .with_run("b", |r| assert_eq!(r.drops, ["custom-new"]))
+            .unwrap();
+
+        assert_eq!(cache.delete(&db, &["a", "c", "missing"]).unwrap(), 2);
+        assert_in_sync(&cache, &db);
+        assert!(cache.with_run("c", |_| ()).is_err());
+
+        // A restored run goes to the end, like its new row
+        cache.restore(&db, "a").unwrap();
+        assert_in_sync(&cache, &db);
+        assert_eq!(cache.read().runs.last().unwrap().id, "a");
+        assert!(cache.restore(&db, "a").is_err());
+
+        cache.clear(&db).unwrap();
+        assert_in_sync(&cache, &db);
+        assert_eq!(db.get_trash().unwrap().len(), 5);
+    }
+}
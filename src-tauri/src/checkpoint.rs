@@ -0,0 +1,54 @@
+//! Checkpoint of the run in progress.
+//!
+//! Only finished runs reach the database, so the active run is snapshotted to
+//! `active_run.json` while it is recorded. A checkpoint still on disk at launch
+//! means the app was closed mid-run; the user can then resume or discard it.
+
+use crate::error::{AppError, AppResult};
+use crate::migrations::migrate_checkpoint;
+use crate::models::RunCheckpoint;
+use crate::utils::{get_checkpoint_path, load_json, quarantine_file, write_atomic};
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Writes the checkpoint, replacing any previous one.
+///
+/// # Arguments
+/// * `dir` - The application data directory path.
+/// * `checkpoint` - Snapshot of the active run.
+pub fn save(dir: &Path, checkpoint: &RunCheckpoint) -> AppResult<()> {
+    let json = serde_json::to_string(checkpoint)?;
+    write_atomic(&get_checkpoint_path(dir), json)?;
+    Ok(())
+}
+
+/// Reads the checkpoint left by a previous run, if any.
+///
+/// Checkpoints written by older builds are migrated to the current schema, and
+/// ones written by a newer build are rejected. A corrupted checkpoint is moved
+/// aside and treated as absent.
+pub fn load(dir: &Path) -> AppResult<Option<RunCheckpoint>> {
+    let path = get_checkpoint_path(dir);
+    let value: Value = match load_json(&path) {
+        Ok(Some(value)) => value,
+        Ok(None) | Err(AppError::Corrupted(_)) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    match serde_json::from_value(migrate_checkpoint(value)?) {
+        Ok(checkpoint) => Ok(Some(checkpoint)),
+        Err(_) => {
+            quarantine_file(&path)?;
+            Ok(None)
+        }
+    }
+}
+
+/// Deletes the checkpoint once its run was saved or discarded.
+pub fn clear(dir: &Path) -> AppResult<()> {
+    match fs::remove_file(get_checkpoint_path(dir)) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
@@ -3,145 +3,1035 @@
 //! This module contains all the IPC commands that can be invoked from the frontend.
 //! Commands are organized into categories: Run Management, Configuration, and Window Control.
 
-use crate::models::{AppConfig, HistoryFilter, RunRecord};
-use crate::utils::{get_cloud_runs_path, get_config_path, get_runs_path, AppState};
+use crate::analytics::{self, DropRateComparison, DropRateGroup, DropTarget, SceneGroup};
+use crate::checkpoint;
+use crate::custom_items::{self, CustomItem, CustomItemInfo};
+use crate::error::{AppError, AppResult};
+use crate::hotkeys::{self, Severity, ShortcutDiagnostic};
+use crate::items::{self, ItemEntry};
+use crate::migrations::CONFIG_SCHEMA_VERSION;
+use crate::models::{
+    AppConfig, HistoryFilter, KeyBinding, KeyEventPayload, RunCheckpoint, RunPage, RunPatch,
+    RunQuery, RunRecord, RunTimeline, Session, SessionDetail, SessionSummary, TrashedRun,
+};
+use crate::scenes::{self, Scene, SceneInfo, CUSTOM_SCENE_PREFIX};
+use crate::segments;
+use crate::stats::{RunStats, SegmentReport};
+use crate::timer::{self, TimerSnapshot, TIMER_STATE_EVENT};
+use crate::utils::{get_config_path, load_config, write_atomic, AppState};
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use tauri::{PhysicalPosition, PhysicalSize};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, PhysicalPosition, PhysicalSize};
 
 // ============================================================================
 // Run Management Commands
 // ============================================================================
 
-/// Retrieves run records with optional filtering.
+/// Retrieves one sorted page of run records with optional filtering.
 ///
 /// # Arguments
-/// * `filter` - Optional filter criteria for date range and scene.
-/// * `state` - Application state containing the data directory path.
+/// * `filter` - Optional filter criteria.
+/// * `query` - Optional sort key, direction, offset and limit (newest first, unlimited by default).
+/// * `state` - Application state containing the in-memory run cache.
 ///
 /// # Returns
-/// A vector of run records matching the filter criteria.
+/// The requested page and the total number of matching runs.
 #[tauri::command]
-pub fn get_runs(filter: Option<HistoryFilter>, state: tauri::State<AppState>) -> Vec<RunRecord> {
-    let path = get_runs_path(&state.app_data_dir);
-    if !path.exists() {
-        return Vec::new();
-    }
+pub fn get_runs(
+    filter: Option<HistoryFilter>,
+    query: Option<RunQuery>,
+    state: tauri::State<AppState>,
+) -> AppResult<RunPage> {
+    Ok(state.runs.query(
+        filter.as_ref(),
+        &state.qualities.read(),
+        &query.unwrap_or_default(),
+    ))
+}
 
-    let data = fs::read_to_string(path).unwrap_or_else(|_| "[]".to_string());
-    let runs: Vec<RunRecord> = serde_json::from_str(&data).unwrap_or_default();
-
-    match filter {
-        Some(f) => runs
-            .into_iter()
-            .filter(|r| {
-                // Filter by scene ID
-                if let Some(ref sid) = f.sceneId {
-                    if sid != "all" && &r.scene_id != sid {
-                        return false;
-                    }
-                }
-                // Filter by start date
-                if let Some(ref s) = f.startStr {
-                    if &r.date_str < s {
-                        return false;
-                    }
-                }
-                // Filter by end date
-                if let Some(ref e) = f.endStr {
-                    if &r.date_str > e {
-                        return false;
-                    }
-                }
-                true
-            })
-            .collect(),
-        None => runs,
-    }
+/// Returns the event timeline of a saved run.
+///
+/// # Arguments
+/// * `id` - The unique identifier of the run.
+/// * `state` - Application state containing the in-memory run cache.
+///
+/// # Returns
+/// The run's start, pause/resume, drop, split and finish events with their
+/// pause-adjusted offsets. Runs saved without a timeline only get a start and
+/// finish event derived from their timestamp and duration.
+#[tauri::command]
+pub fn get_run_timeline(id: String, state: tauri::State<AppState>) -> AppResult<RunTimeline> {
+    state.runs.with_run(&id, RunTimeline::for_run)
 }
 
 /// Saves a new run record to the database.
 ///
+/// The checkpoint is only cleared if it belongs to this run, so saving a
+/// manual entry does not lose a run in progress or waiting to be resumed.
+///
 /// # Arguments
 /// * `run` - The run record to save.
-/// * `state` - Application state containing the data directory path.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// `Ok(())` once the run is stored, or an [`AppError`](crate::error::AppError)
+/// the frontend can show as "run not saved".
 #[tauri::command]
-pub fn save_run(run: RunRecord, state: tauri::State<AppState>) {
-    let path = get_runs_path(&state.app_data_dir);
-    let mut runs: Vec<RunRecord> = if path.exists() {
-        serde_json::from_str(&fs::read_to_string(&path).unwrap_or_else(|_| "[]".to_string()))
-            .unwrap_or_default()
-    } else {
-        Vec::new()
-    };
+pub fn save_run(mut run: RunRecord, state: tauri::State<AppState>) -> AppResult<()> {
+    run.scene_id = scenes::canonical_id(&run.scene_id);
+    let pending = checkpoint::load(&state.app_data_dir)?;
+    let finishes_pending = pending.is_some_and(|cp| cp.run_id.as_ref() == Some(&run.id));
+    state.runs.insert(&state.db, run)?;
+    if finishes_pending {
+        checkpoint::clear(&state.app_data_dir)?;
+    }
+    Ok(())
+}
 
-    runs.push(run);
-    let _ = fs::write(path, serde_json::to_string_pretty(&runs).unwrap());
+/// Updates fields of a saved run record.
+///
+/// Only `drops`, `scene_id`, `is_tz` and `duration_ms` can be changed. The
+/// previous values are kept in the run's `edits` history. Legacy scene names
+/// are mapped to scene IDs; unknown scenes are rejected.
+///
+/// # Arguments
+/// * `id` - The unique identifier of the run to update.
+/// * `patch` - The fields to change.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The updated run record.
+#[tauri::command]
+pub fn update_run(
+    id: String,
+    mut patch: RunPatch,
+    state: tauri::State<AppState>,
+) -> AppResult<RunRecord> {
+    patch.validate()?;
+    if let Some(scene_id) = patch.scene_id.as_mut() {
+        *scene_id = scenes::resolve(&state.db, scene_id)?;
+    }
+    state.runs.update(&state.db, &id, &patch)
 }
 
 /// Deletes a run record by its ID.
 ///
+/// The run is moved to the trash and can be brought back with `restore_run`
+/// until the trash is emptied or the retention period expires.
+///
 /// # Arguments
 /// * `id` - The unique identifier of the run to delete.
-/// * `state` - Application state containing the data directory path.
+/// * `state` - Application state containing the run database.
+#[tauri::command]
+pub fn delete_run(id: String, state: tauri::State<AppState>) -> AppResult<()> {
+    state.runs.delete(&state.db, &[&id])?;
+    Ok(())
+}
+
+/// Clears all run records from local storage.
+///
+/// This is used after syncing data to the cloud. The runs are moved to the
+/// trash, so they can still be restored if the sync turns out to have failed.
+///
+/// # Arguments
+/// * `state` - Application state containing the run database.
+#[tauri::command]
+pub fn clear_runs(state: tauri::State<AppState>) -> AppResult<()> {
+    state.runs.clear(&state.db)
+}
+
+/// Reports a legacy JSON file that could not be imported at startup.
+///
+/// # Arguments
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// `Ok(())`, or [`AppError::Corrupted`] naming the file and where it was
+/// moved, so it can be repaired and put back for the next launch.
 #[tauri::command]
-pub fn delete_run(id: String, state: tauri::State<AppState>) {
-    let path = get_runs_path(&state.app_data_dir);
-    if !path.exists() {
-        return;
+pub fn check_legacy_import(state: tauri::State<AppState>) -> AppResult<()> {
+    match state.db.legacy_import_error() {
+        Some(message) => Err(AppError::Corrupted(message.to_string())),
+        None => Ok(()),
     }
+}
 
-    let mut runs: Vec<RunRecord> =
-        serde_json::from_str(&fs::read_to_string(&path).unwrap_or_else(|_| "[]".to_string()))
-            .unwrap_or_default();
+// ============================================================================
+// Bulk Run Commands
+// ============================================================================
+
+/// Deletes several runs in one transaction.
+///
+/// Like `delete_run`, the runs are moved to the trash.
+///
+/// # Arguments
+/// * `ids` - The unique identifiers of the runs to delete.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The number of runs deleted.
+#[tauri::command]
+pub fn delete_runs(ids: Vec<String>, state: tauri::State<AppState>) -> AppResult<usize> {
+    let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+    state.runs.delete(&state.db, &ids)
+}
+
+/// Changes the scene of every run matching a filter.
+///
+/// Each changed run records the previous scene in its edit history. Like
+/// `update_run`, legacy scene names are mapped to scene IDs and unknown
+/// scenes are rejected.
+///
+/// # Arguments
+/// * `filter` - Selects the runs to re-tag.
+/// * `scene_id` - The new scene identifier.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The number of runs changed.
+#[tauri::command]
+pub fn retag_runs(
+    filter: HistoryFilter,
+    scene_id: String,
+    state: tauri::State<AppState>,
+) -> AppResult<usize> {
+    let patch = RunPatch {
+        scene_id: Some(scenes::resolve(&state.db, &scene_id)?),
+        ..RunPatch::default()
+    };
+    let qualities = state.qualities.read();
+    state
+        .runs
+        .update_where(&state.db, |r| filter.matches(r, &qualities), &patch)
+}
+
+/// Sets the Terror Zone flag on several runs.
+///
+/// # Arguments
+/// * `ids` - The unique identifiers of the runs to change.
+/// * `is_tz` - The new Terror Zone flag.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The number of runs changed.
+#[tauri::command]
+pub fn set_runs_tz(
+    ids: Vec<String>,
+    is_tz: bool,
+    state: tauri::State<AppState>,
+) -> AppResult<usize> {
+    let ids: HashSet<String> = ids.into_iter().collect();
+    let patch = RunPatch {
+        is_tz: Some(is_tz),
+        ..RunPatch::default()
+    };
+    state
+        .runs
+        .update_where(&state.db, |r| ids.contains(&r.id), &patch)
+}
+
+// ============================================================================
+// Trash Commands
+// ============================================================================
+
+/// Lists the runs in the trash, most recently deleted first.
+///
+/// Runs past the retention period are purged first, so the trash does not
+/// only shrink when the app restarts.
+///
+/// # Arguments
+/// * `state` - Application state containing the run database.
+#[tauri::command]
+pub fn get_trash(state: tauri::State<AppState>) -> AppResult<Vec<TrashedRun>> {
+    state.purge_expired_trash()?;
+    Ok(state.db.get_trash()?)
+}
+
+/// Restores a run from the trash.
+///
+/// # Arguments
+/// * `id` - The unique identifier of the deleted run.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The restored run record.
+#[tauri::command]
+pub fn restore_run(id: String, state: tauri::State<AppState>) -> AppResult<RunRecord> {
+    state.runs.restore(&state.db, &id)
+}
+
+/// Permanently deletes every run in the trash.
+///
+/// # Arguments
+/// * `state` - Application state containing the run database.
+#[tauri::command]
+pub fn empty_trash(state: tauri::State<AppState>) -> AppResult<()> {
+    state.db.empty_trash()?;
+    Ok(())
+}
+
+/// Retrieves cloud run records from the local cache.
+///
+/// # Arguments
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// A vector of run records from the cloud cache.
+#[tauri::command]
+pub fn get_cloud_runs(state: tauri::State<AppState>) -> AppResult<Vec<RunRecord>> {
+    Ok(state.db.get_cloud_runs()?)
+}
 
-    if let Some(idx) = runs.iter().position(|r| r.id == id) {
-        runs.remove(idx);
-        let _ = fs::write(path, serde_json::to_string_pretty(&runs).unwrap());
+/// Replaces the local cloud run cache.
+///
+/// Custom item IDs are local to the install that created them, so the
+/// definitions of the items the runs refer to (see `get_custom_items`) are
+/// synced with them. Unknown items are added here; ones matching a local
+/// item by name and quality are mapped to it.
+///
+/// # Arguments
+/// * `runs` - The run records to save.
+/// * `custom_items` - Definitions of the custom items the runs refer to.
+/// * `state` - Application state containing the run database.
+#[tauri::command]
+pub fn save_cloud_runs(
+    mut runs: Vec<RunRecord>,
+    custom_items: Option<Vec<CustomItem>>,
+    state: tauri::State<AppState>,
+) -> AppResult<()> {
+    let mapped = custom_items::adopt(
+        &state.db,
+        &state.qualities,
+        custom_items.unwrap_or_default(),
+    )?;
+    for run in &mut runs {
+        // Cloud data may still use legacy scene names
+        run.scene_id = scenes::canonical_id(&run.scene_id);
+        for (from, to) in &mapped {
+            run.replace_drop_id(from, to);
+        }
     }
+    Ok(state.db.replace_cloud_runs(&runs)?)
 }
 
-/// Clears all run records from local storage.
+// ============================================================================
+// Run Timer Commands
+// ============================================================================
+
+/// Returns the current run timer state.
+///
+/// # Arguments
+/// * `state` - Application state containing the run timer.
+#[tauri::command]
+pub fn timer_state(state: tauri::State<AppState>) -> TimerSnapshot {
+    state.timer.snapshot()
+}
+
+/// Starts timing a new run, discarding any run in progress.
 ///
-/// This is used after syncing data to the cloud.
+/// # Arguments
+/// * `scene_id` - Scene of the run.
+/// * `is_tz` - Whether this is a Terror Zone run.
+/// * `session_id` - The session the run belongs to, if any.
+/// * `app` - Handle used to emit the new timer state.
+/// * `state` - Application state containing the run timer.
+#[tauri::command]
+pub fn timer_start(
+    scene_id: String,
+    is_tz: bool,
+    session_id: Option<String>,
+    app: AppHandle,
+    state: tauri::State<AppState>,
+) -> AppResult<TimerSnapshot> {
+    let snapshot = state.timer.start(scene_id, is_tz, session_id);
+    Ok(timer::publish(&app, &state, snapshot))
+}
+
+/// Pauses the run in progress.
 ///
 /// # Arguments
-/// * `state` - Application state containing the data directory path.
+/// * `app` - Handle used to emit the new timer state.
+/// * `state` - Application state containing the run timer.
+#[tauri::command]
+pub fn timer_pause(app: AppHandle, state: tauri::State<AppState>) -> AppResult<TimerSnapshot> {
+    let snapshot = state.timer.pause()?;
+    Ok(timer::publish(&app, &state, snapshot))
+}
+
+/// Resumes the paused run.
+///
+/// # Arguments
+/// * `app` - Handle used to emit the new timer state.
+/// * `state` - Application state containing the run timer.
+#[tauri::command]
+pub fn timer_resume(app: AppHandle, state: tauri::State<AppState>) -> AppResult<TimerSnapshot> {
+    let snapshot = state.timer.resume()?;
+    Ok(timer::publish(&app, &state, snapshot))
+}
+
+/// Records a split at the current elapsed time.
+///
+/// # Arguments
+/// * `app` - Handle used to emit the new timer state.
+/// * `state` - Application state containing the run timer.
+#[tauri::command]
+pub fn timer_split(app: AppHandle, state: tauri::State<AppState>) -> AppResult<TimerSnapshot> {
+    let snapshot = state.timer.split()?;
+    Ok(timer::publish(&app, &state, snapshot))
+}
+
+/// Replaces the drops of the run in progress; the run is checkpointed right away.
+///
+/// # Arguments
+/// * `drops` - All drops recorded so far.
+/// * `app` - Handle used to emit the new timer state.
+/// * `state` - Application state containing the run timer.
+#[tauri::command]
+pub fn timer_set_drops(
+    drops: Vec<String>,
+    app: AppHandle,
+    state: tauri::State<AppState>,
+) -> AppResult<TimerSnapshot> {
+    let snapshot = state.timer.set_drops(drops)?;
+    Ok(timer::publish(&app, &state, snapshot))
+}
+
+/// Changes the Terror Zone flag of the run in progress.
+///
+/// # Arguments
+/// * `is_tz` - The new Terror Zone flag.
+/// * `app` - Handle used to emit the new timer state.
+/// * `state` - Application state containing the run timer.
+#[tauri::command]
+pub fn timer_set_tz(
+    is_tz: bool,
+    app: AppHandle,
+    state: tauri::State<AppState>,
+) -> AppResult<TimerSnapshot> {
+    let snapshot = state.timer.set_tz(is_tz)?;
+    Ok(timer::publish(&app, &state, snapshot))
+}
+
+/// Stops the timer and saves the finished run if it is long enough.
+///
+/// If saving fails, the run's checkpoint is kept so it can be resumed on
+/// the next launch.
+///
+/// # Arguments
+/// * `min_duration_ms` - Runs shorter than this are discarded.
+/// * `app` - Handle used to emit the new timer state.
+/// * `state` - Application state containing the run timer and cache.
+///
+/// # Returns
+/// The saved run, or `None` if no run was in progress or it was too short.
 #[tauri::command]
-pub fn clear_runs(state: tauri::State<AppState>) {
-    let path = get_runs_path(&state.app_data_dir);
-    if path.exists() {
-        // Write empty array to clear all runs
-        let _ = fs::write(path, "[]");
+pub fn timer_finish(
+    min_duration_ms: i64,
+    app: AppHandle,
+    state: tauri::State<AppState>,
+) -> AppResult<Option<RunRecord>> {
+    // Keep the ticker from writing a checkpoint of this run after it is cleared
+    let _saving = state.timer.lock_checkpoints();
+    let run = state.timer.finish().map(|mut run| {
+        run.segments = segments::for_run(&scene_segment_names(&state, &run.scene_id), &run);
+        run
+    });
+    let _ = app.emit(TIMER_STATE_EVENT, state.timer.snapshot());
+
+    let run = run.filter(|r| r.duration_ms >= min_duration_ms);
+    if let Some(ref run) = run {
+        state.runs.insert(&state.db, run.clone())?;
     }
+    checkpoint::clear(&state.app_data_dir)?;
+    Ok(run)
 }
 
-/// Retrieves cloud run records from the local cache file.
+/// Returns the segment names of a scene from the config or the built-ins.
+fn scene_segment_names(state: &AppState, scene_id: &str) -> Vec<String> {
+    let defined = scenes::find(&state.db, scene_id)
+        .ok()
+        .flatten()
+        .map(|s| s.info.segments)
+        .unwrap_or_default();
+    segments::names_for(&state.config(), scene_id, &defined)
+}
+
+/// Returns the segment names defined for a scene.
+///
+/// # Arguments
+/// * `scene_id` - The scene identifier.
+/// * `state` - Application state containing the config and scene registry.
+///
+/// # Returns
+/// The names in split order, or an empty list if the scene has no segments.
+#[tauri::command]
+pub fn get_scene_segments(scene_id: String, state: tauri::State<AppState>) -> Vec<String> {
+    scene_segment_names(&state, &scene_id)
+}
+
+/// Returns the run left in progress when the app was last closed, if any.
 ///
 /// # Arguments
 /// * `state` - Application state containing the data directory path.
+#[tauri::command]
+pub fn get_pending_run(state: tauri::State<AppState>) -> AppResult<Option<RunCheckpoint>> {
+    checkpoint::load(&state.app_data_dir)
+}
+
+/// Continues the run left in progress when the app was last closed.
+///
+/// # Arguments
+/// * `app` - Handle used to emit the new timer state.
+/// * `state` - Application state containing the run timer.
 ///
 /// # Returns
-/// A vector of run records from the cloud cache.
+/// The restored timer state, or `None` if there was no pending run.
+#[tauri::command]
+pub fn restore_pending_run(
+    app: AppHandle,
+    state: tauri::State<AppState>,
+) -> AppResult<Option<TimerSnapshot>> {
+    let Some(cp) = checkpoint::load(&state.app_data_dir)? else {
+        return Ok(None);
+    };
+    let snapshot = state.timer.restore(cp);
+    Ok(Some(timer::publish(&app, &state, snapshot)))
+}
+
+/// Discards the checkpoint of the run in progress.
+///
+/// # Arguments
+/// * `state` - Application state containing the data directory path.
+#[tauri::command]
+pub fn discard_pending_run(state: tauri::State<AppState>) -> AppResult<()> {
+    checkpoint::clear(&state.app_data_dir)
+}
+
+// ============================================================================
+// Item Commands
+// ============================================================================
+
+/// Maximum number of results `search_items` returns.
+const MAX_SEARCH_RESULTS: usize = 50;
+
+/// Returns the full item catalog.
+///
+/// # Returns
+/// Every catalog item with its rarity class, in catalog order.
+#[tauri::command]
+pub fn get_items() -> Vec<ItemEntry> {
+    items::catalog().iter().map(ItemEntry::from).collect()
+}
+
+/// Searches the item catalog for the drop search box.
+///
+/// Matches Chinese names, pinyin initials (e.g. "hd" for 混沌) and English
+/// names, best matches first.
+///
+/// # Arguments
+/// * `query` - The search text.
+/// * `limit` - Maximum number of results (default and cap: 50).
+///
+/// # Returns
+/// Matching items with their rarity class.
+#[tauri::command]
+pub fn search_items(query: String, limit: Option<usize>) -> Vec<ItemEntry> {
+    let limit = limit.unwrap_or(MAX_SEARCH_RESULTS).min(MAX_SEARCH_RESULTS);
+    items::search(&query, limit)
+        .into_iter()
+        .map(ItemEntry::from)
+        .collect()
+}
+
+/// Returns all custom items.
+///
+/// # Arguments
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The custom items, ordered by English name.
+#[tauri::command]
+pub fn get_custom_items(state: tauri::State<AppState>) -> AppResult<Vec<CustomItem>> {
+    Ok(state.db.get_custom_items()?)
+}
+
+/// Creates a custom item to record as a drop.
+///
+/// An existing item with the same name and quality is returned instead of
+/// creating a duplicate, so recording the same base twice counts as the
+/// same item.
+///
+/// # Arguments
+/// * `info` - Names, quality, base type and notes of the item.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The new or existing item.
 #[tauri::command]
-pub fn get_cloud_runs(state: tauri::State<AppState>) -> Vec<RunRecord> {
-    let path = get_cloud_runs_path(&state.app_data_dir);
-    if !path.exists() {
-        return Vec::new();
+pub fn create_custom_item(
+    info: CustomItemInfo,
+    state: tauri::State<AppState>,
+) -> AppResult<CustomItem> {
+    let info = info.normalized()?;
+    if let Some(existing) = state
+        .db
+        .get_custom_items()?
+        .into_iter()
+        .find(|item| item.info.same_item(&info))
+    {
+        return Ok(existing);
     }
 
-    let data = fs::read_to_string(path).unwrap_or_else(|_| "[]".to_string());
-    serde_json::from_str(&data).unwrap_or_default()
+    let item = CustomItem::new(info, chrono::Utc::now().timestamp_millis());
+    state.db.write_custom_item(&item)?;
+    state.qualities.reload(&state.db)?;
+    Ok(item)
 }
 
-/// Saves cloud run records to the local cache file.
+/// Changes a custom item. Its ID, and so every run it dropped in, is kept.
 ///
 /// # Arguments
-/// * `runs` - The run records to save.
-/// * `state` - Application state containing the data directory path.
+/// * `id` - The ID of the item to change.
+/// * `info` - The new names, quality, base type and notes.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The updated item.
+#[tauri::command]
+pub fn update_custom_item(
+    id: String,
+    info: CustomItemInfo,
+    state: tauri::State<AppState>,
+) -> AppResult<CustomItem> {
+    let mut item = find_custom_item(&state, &id)?;
+    item.info = info.normalized()?;
+    state.db.write_custom_item(&item)?;
+    state.qualities.reload(&state.db)?;
+    Ok(item)
+}
+
+/// Deletes a custom item that no run refers to, including trashed and
+/// cached cloud runs.
+///
+/// # Arguments
+/// * `id` - The ID of the item to delete.
+/// * `state` - Application state containing the run database.
+#[tauri::command]
+pub fn delete_custom_item(id: String, state: tauri::State<AppState>) -> AppResult<()> {
+    find_custom_item(&state, &id)?;
+    let runs = state.db.count_custom_item_runs(&id)?;
+    if runs > 0 {
+        return Err(AppError::Validation(format!(
+            "item is used by {} runs, including trashed and cloud runs; \
+             merge it into another item instead",
+            runs
+        )));
+    }
+    state.db.delete_custom_item(&id)?;
+    state.qualities.reload(&state.db)?;
+    Ok(())
+}
+
+/// Merges one custom item into another: every run that refers to the
+/// source refers to the target afterwards, and the source is deleted.
+///
+/// # Arguments
+/// * `source_id` - The item to merge away.
+/// * `target_id` - The item to keep.
+/// * `state` - Application state containing the run database and cache.
+///
+/// # Returns
+/// The number of runs changed.
 #[tauri::command]
-pub fn save_cloud_runs(runs: Vec<RunRecord>, state: tauri::State<AppState>) {
-    let path = get_cloud_runs_path(&state.app_data_dir);
-    let _ = fs::write(path, serde_json::to_string_pretty(&runs).unwrap());
+pub fn merge_custom_items(
+    source_id: String,
+    target_id: String,
+    state: tauri::State<AppState>,
+) -> AppResult<usize> {
+    if source_id == target_id {
+        return Err(AppError::Validation(
+            "cannot merge an item into itself".to_string(),
+        ));
+    }
+    find_custom_item(&state, &source_id)?;
+    find_custom_item(&state, &target_id)?;
+    let changed = state
+        .runs
+        .merge_custom_items(&state.db, &source_id, &target_id)?;
+    state.qualities.reload(&state.db)?;
+    Ok(changed)
+}
+
+/// Loads a custom item, or fails with `NotFound`.
+fn find_custom_item(state: &AppState, id: &str) -> AppResult<CustomItem> {
+    state
+        .db
+        .get_custom_item(id)?
+        .ok_or_else(|| AppError::NotFound(format!("custom item {}", id)))
+}
+
+// ============================================================================
+// Scene Commands
+// ============================================================================
+
+/// Returns all scenes: built-in ones first, then custom ones.
+///
+/// # Arguments
+/// * `state` - Application state containing the run database.
+#[tauri::command]
+pub fn get_scenes(state: tauri::State<AppState>) -> AppResult<Vec<Scene>> {
+    scenes::all(&state.db)
+}
+
+/// Creates a custom scene.
+///
+/// # Arguments
+/// * `info` - Names, act, area and segments of the scene.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The new scene with its generated ID.
+#[tauri::command]
+pub fn create_scene(info: SceneInfo, state: tauri::State<AppState>) -> AppResult<Scene> {
+    let scene = Scene {
+        id: format!("{}{}", CUSTOM_SCENE_PREFIX, uuid::Uuid::new_v4()),
+        custom: true,
+        info: info.normalized()?,
+    };
+    state.db.write_scene(&scene)?;
+    Ok(scene)
+}
+
+/// Changes a custom scene. Its ID, and so its run history, is kept.
+///
+/// # Arguments
+/// * `id` - The ID of the scene to change.
+/// * `info` - The new names, act, area and segments.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The updated scene. Built-in scenes cannot be changed.
+#[tauri::command]
+pub fn update_scene(
+    id: String,
+    info: SceneInfo,
+    state: tauri::State<AppState>,
+) -> AppResult<Scene> {
+    let mut scene = find_custom_scene(&state, &id)?;
+    scene.info = info.normalized()?;
+    state.db.write_scene(&scene)?;
+    Ok(scene)
+}
+
+/// Deletes a custom scene that no run or session refers to, including
+/// trashed and cached cloud runs.
+///
+/// # Arguments
+/// * `id` - The ID of the scene to delete.
+/// * `state` - Application state containing the run database.
+#[tauri::command]
+pub fn delete_scene(id: String, state: tauri::State<AppState>) -> AppResult<()> {
+    find_custom_scene(&state, &id)?;
+    let uses = state.db.count_scene_uses(&id)?;
+    if uses > 0 {
+        return Err(AppError::Validation(format!(
+            "scene is used by {} runs or sessions, including trashed and cloud runs; \
+             move or delete them first",
+            uses
+        )));
+    }
+    state.db.delete_scene(&id)?;
+    Ok(())
+}
+
+/// Loads a custom scene, or fails with `NotFound` or, for a built-in
+/// scene, `Validation`.
+fn find_custom_scene(state: &AppState, id: &str) -> AppResult<Scene> {
+    if scenes::is_builtin(id) {
+        return Err(AppError::Validation(
+            "built-in scenes cannot be changed".to_string(),
+        ));
+    }
+    state
+        .db
+        .get_scene(id)?
+        .ok_or_else(|| AppError::NotFound(format!("scene {}", id)))
+}
+
+// ============================================================================
+// Session Commands
+// ============================================================================
+
+/// Starts a new session, ending any session still open.
+///
+/// # Arguments
+/// * `scene_id` - The scene the session is started in; legacy scene names
+///   are mapped to scene IDs.
+/// * `is_tz` - Whether this is a Terror Zone session.
+/// * `notes` - Optional initial notes.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The new session; its ID is set on runs saved during the session.
+#[tauri::command]
+pub fn start_session(
+    scene_id: String,
+    is_tz: bool,
+    notes: Option<String>,
+    state: tauri::State<AppState>,
+) -> AppResult<Session> {
+    let session = Session {
+        id: uuid::Uuid::new_v4().to_string(),
+        scene_id: scenes::resolve(&state.db, &scene_id)?,
+        started_at: chrono::Utc::now().timestamp_millis(),
+        ended_at: None,
+        is_tz,
+        notes: notes.unwrap_or_default(),
+    };
+    state.db.start_session(&session)?;
+    Ok(session)
+}
+
+/// Ends a session now.
+///
+/// # Arguments
+/// * `id` - The ID of the session to end.
+/// * `state` - Application state containing the run database.
+///
+/// # Returns
+/// The ended session, or a `NotFound` error if no session has this ID.
+#[tauri::command]
+pub fn end_session(id: String, state: tauri::State<AppState>) -> AppResult<Session> {
+    let mut session = find_session(&state, &id)?;
+    if session.ended_at.is_none() {
+        session.ended_at = Some(chrono::Utc::now().timestamp_millis());
+        state.db.update_session(&session)?;
+    }
+    Ok(session)
+}
+
+/// Replaces the notes of a session.
+///
+/// # Arguments
+/// * `id` - The ID of the session to change.
+/// * `notes` - The new notes.
+/// * `state` - Application state containing the run database.
+#[tauri::command]
+pub fn set_session_notes(
+    id: String,
+    notes: String,
+    state: tauri::State<AppState>,
+) -> AppResult<Session> {
+    let mut session = find_session(&state, &id)?;
+    session.notes = notes;
+    state.db.update_session(&session)?;
+    Ok(session)
+}
+
+/// Lists all sessions with their aggregate statistics, most recent first.
+///
+/// # Arguments
+/// * `state` - Application state containing the run database and cache.
+#[tauri::command]
+pub fn get_sessions(state: tauri::State<AppState>) -> AppResult<Vec<SessionSummary>> {
+    let sessions = state.db.get_sessions()?;
+    Ok(state
+        .runs
+        .with_matching(None, &state.qualities.read(), |runs| {
+            SessionSummary::for_sessions(sessions, runs)
+        }))
+}
+
+/// Opens a session with its statistics and runs.
+///
+/// # Arguments
+/// * `id` - The ID of the session to open.
+/// * `state` - Application state containing the run database and cache.
+///
+/// # Returns
+/// The session, or a `NotFound` error if no session has this ID.
+#[tauri::command]
+pub fn get_session(id: String, state: tauri::State<AppState>) -> AppResult<SessionDetail> {
+    let session = find_session(&state, &id)?;
+    let filter = HistoryFilter {
+        sessionId: Some(id),
+        ..Default::default()
+    };
+    Ok(state
+        .runs
+        .with_matching(Some(&filter), &state.qualities.read(), |runs| {
+            SessionDetail {
+                runs: runs.iter().map(|&r| r.clone()).collect(),
+                summary: SessionSummary::new(session, runs),
+            }
+        }))
+}
+
+/// Deletes a session and moves its runs to the trash.
+///
+/// # Arguments
+/// * `id` - The ID of the session to delete.
+/// * `state` - Application state containing the run database and cache.
+///
+/// # Returns
+/// The number of runs moved to the trash.
+#[tauri::command]
+pub fn delete_session(id: String, state: tauri::State<AppState>) -> AppResult<usize> {
+    find_session(&state, &id)?;
+    state.runs.delete_session(&state.db, &id)
+}
+
+/// Merges one session into another.
+///
+/// The target keeps its ID and scene; its time range grows to cover both
+/// sessions and the source's notes are appended. The source is deleted.
+///
+/// # Arguments
+/// * `source_id` - The session to merge away.
+/// * `target_id` - The session receiving the runs.
+/// * `state` - Application state containing the run database and cache.
+///
+/// # Returns
+/// The merged session with its statistics.
+#[tauri::command]
+pub fn merge_sessions(
+    source_id: String,
+    target_id: String,
+    state: tauri::State<AppState>,
+) -> AppResult<SessionSummary> {
+    if source_id == target_id {
+        return Err(AppError::Validation(
+            "cannot merge a session into itself".to_string(),
+        ));
+    }
+    let source = find_session(&state, &source_id)?;
+    let mut target = find_session(&state, &target_id)?;
+
+    target.started_at = target.started_at.min(source.started_at);
+    target.ended_at = target.ended_at.zip(source.ended_at).map(|(a, b)| a.max(b));
+    if !source.notes.is_empty() {
+        if !target.notes.is_empty() {
+            target.notes.push('\n');
+        }
+        target.notes.push_str(&source.notes);
+    }
+    state.runs.merge_sessions(&state.db, &source_id, &target)?;
+
+    let filter = HistoryFilter {
+        sessionId: Some(target_id),
+        ..Default::default()
+    };
+    Ok(state
+        .runs
+        .with_matching(Some(&filter), &state.qualities.read(), |runs| {
+            SessionSummary::new(target, runs)
+        }))
+}
+
+/// Loads a session, or fails with `NotFound`.
+fn find_session(state: &AppState, id: &str) -> AppResult<Session> {
+    state
+        .db
+        .get_session(id)?
+        .ok_or_else(|| AppError::NotFound(format!("session {}", id)))
+}
+
+// ============================================================================
+// Statistics Commands
+// ============================================================================
+
+/// Computes aggregate statistics over local runs.
+///
+/// # Arguments
+/// * `filter` - Optional filter criteria selecting the runs to aggregate.
+/// * `state` - Application state containing the in-memory run cache.
+///
+/// # Returns
+/// Per-scene duration statistics, drop counts by rarity and the TZ split.
+#[tauri::command]
+pub fn get_stats(
+    filter: Option<HistoryFilter>,
+    state: tauri::State<AppState>,
+) -> AppResult<RunStats> {
+    let qualities = state.qualities.read();
+    Ok(state
+        .runs
+        .with_matching(filter.as_ref(), &qualities, |runs| {
+            RunStats::from_runs(runs, &qualities)
+        }))
+}
+
+/// Computes per-segment statistics for one scene over local runs.
+///
+/// # Arguments
+/// * `scene_id` - The scene to analyze.
+/// * `filter` - Optional filter criteria selecting the runs to analyze.
+/// * `state` - Application state containing the in-memory run cache.
+///
+/// # Returns
+/// Best, average and other duration statistics per segment, plus the sum of
+/// best segments.
+#[tauri::command]
+pub fn get_segment_stats(
+    scene_id: String,
+    filter: Option<HistoryFilter>,
+    state: tauri::State<AppState>,
+) -> AppResult<SegmentReport> {
+    let names = scene_segment_names(&state, &scene_id);
+    Ok(state
+        .runs
+        .with_matching(filter.as_ref(), &state.qualities.read(), |runs| {
+            SegmentReport::from_runs(&scene_id, &names, runs)
+        }))
+}
+
+/// Computes drop rates per scene and TZ flag over local runs.
+///
+/// # Arguments
+/// * `filter` - Optional filter criteria selecting the runs to analyze.
+/// * `state` - Application state containing the in-memory run cache.
+///
+/// # Returns
+/// Per-item and per-rarity drop rates with 95% confidence intervals.
+#[tauri::command]
+pub fn get_drop_rates(
+    filter: Option<HistoryFilter>,
+    state: tauri::State<AppState>,
+) -> AppResult<Vec<DropRateGroup>> {
+    let qualities = state.qualities.read();
+    Ok(state
+        .runs
+        .with_matching(filter.as_ref(), &qualities, |runs| {
+            analytics::drop_rates(runs, &qualities)
+        }))
+}
+
+/// Tests whether a drop target drops more often in one scene than another.
+///
+/// # Arguments
+/// * `a` - The first scene (and optional TZ flag).
+/// * `b` - The second scene (and optional TZ flag).
+/// * `target` - The item or rarity class to compare.
+/// * `filter` - Optional filter criteria applied to both groups.
+/// * `state` - Application state containing the in-memory run cache.
+///
+/// # Returns
+/// Both drop rates, their difference and a two-sided p-value.
+#[tauri::command]
+pub fn compare_drop_rates(
+    a: SceneGroup,
+    b: SceneGroup,
+    target: DropTarget,
+    filter: Option<HistoryFilter>,
+    state: tauri::State<AppState>,
+) -> AppResult<DropRateComparison> {
+    let qualities = state.qualities.read();
+    Ok(state
+        .runs
+        .with_matching(filter.as_ref(), &qualities, |runs| {
+            analytics::compare(runs, &a, &b, target, &qualities)
+        }))
 }
 
 // ============================================================================
@@ -151,6 +1041,9 @@ pub fn save_cloud_runs(runs: Vec<RunRecord>, state: tauri::State<AppState>) {
 /// Retrieves the application configuration.
 ///
 /// If no configuration file exists, returns the default configuration.
+/// Files from older versions are migrated and written back.
+/// A corrupted file is moved aside and reported as an error; the next call
+/// then returns the default configuration.
 /// If the shortcuts map is empty, populates it with defaults.
 ///
 /// # Arguments
@@ -159,36 +1052,60 @@ pub fn save_cloud_runs(runs: Vec<RunRecord>, state: tauri::State<AppState>) {
 /// # Returns
 /// The current application configuration.
 #[tauri::command]
-pub fn get_config(state: tauri::State<AppState>) -> AppConfig {
-    let path = get_config_path(&state.app_data_dir);
-
-    if path.exists() {
-        let content = fs::read_to_string(&path).unwrap_or_else(|_| "{}".to_string());
-
-        let mut config: AppConfig = serde_json::from_str(&content).unwrap_or_default();
-
-        // Ensure shortcuts are populated
-        if config.shortcuts.is_empty() {
-            config.shortcuts = AppConfig::default().shortcuts;
-        }
-
-        config
-    } else {
-        AppConfig::default()
-    }
+pub fn get_config(state: tauri::State<AppState>) -> AppResult<AppConfig> {
+    let config = load_config(&state.app_data_dir)?;
+    state.set_config(config.clone());
+    Ok(config)
 }
 
-/// Saves the application configuration to disk.
+/// Saves the application configuration to disk and applies its shortcuts.
+///
+/// The file is stamped with the current schema version and written atomically,
+/// so a crash never leaves a truncated config.
+///
+/// Shortcuts changed since the last loaded or saved config must pass
+/// [`hotkeys::validate_shortcuts`] without errors; unchanged ones are kept
+/// as they are, so an old invalid binding does not block other settings.
 ///
 /// # Arguments
 /// * `config` - The configuration to save.
 /// * `state` - Application state containing the data directory path.
 #[tauri::command]
-pub fn save_config(config: AppConfig, state: tauri::State<AppState>) {
+pub fn save_config(mut config: AppConfig, state: tauri::State<AppState>) -> AppResult<()> {
     let path = get_config_path(&state.app_data_dir);
+    config.schema_version = CONFIG_SCHEMA_VERSION;
+
+    // Compare with the config held in state, so a corrupted file on disk
+    // does not block saving (or `reset_config`) over it
+    let saved = state.config().shortcuts.clone();
+    let errors: Vec<String> = hotkeys::validate_shortcuts(&config.shortcuts)
+        .into_iter()
+        .filter(|d| d.severity == Severity::Error)
+        .filter(|d| saved.get(&d.action) != config.shortcuts.get(&d.action))
+        .map(|d| format!("{}: {}", d.action, d.issue))
+        .collect();
+    if !errors.is_empty() {
+        return Err(AppError::Validation(errors.join("; ")));
+    }
 
-    let json = serde_json::to_string_pretty(&config).unwrap();
-    let _ = fs::write(&path, &json);
+    let json = serde_json::to_string_pretty(&config)?;
+    write_atomic(&path, json)?;
+    state.hotkeys.set_bindings(&config.shortcuts);
+    state.set_config(config);
+    Ok(())
+}
+
+/// Checks a shortcut table without saving it.
+///
+/// # Arguments
+/// * `shortcuts` - The shortcuts to check, keyed by action name.
+///
+/// # Returns
+/// The issues found, per action. Errors make `save_config` reject the
+/// binding; warnings do not.
+#[tauri::command]
+pub fn validate_shortcuts(shortcuts: HashMap<String, KeyBinding>) -> Vec<ShortcutDiagnostic> {
+    hotkeys::validate_shortcuts(&shortcuts)
 }
 
 /// Resets the configuration to defaults and saves it.
@@ -199,10 +1116,10 @@ pub fn save_config(config: AppConfig, state: tauri::State<AppState>) {
 /// # Returns
 /// The new default configuration.
 #[tauri::command]
-pub fn reset_config(state: tauri::State<AppState>) -> AppConfig {
+pub fn reset_config(state: tauri::State<AppState>) -> AppResult<AppConfig> {
     let config = AppConfig::default();
-    save_config(config.clone(), state);
-    config
+    save_config(config.clone(), state)?;
+    Ok(config)
 }
 
 // ============================================================================
@@ -247,23 +1164,82 @@ pub fn quit_app(app: tauri::AppHandle) {
 // Keyboard Recording Commands
 // ============================================================================
 
-/// Signals the start of keyboard shortcut recording mode.
+/// Records the next key combination pressed anywhere, for a shortcut binding.
 ///
-/// Note: This is intentionally a no-op. Keyboard events are captured
-/// globally via rdev in main.rs and emitted to the frontend.
-/// This command serves as a state signal for the frontend to know
-/// when to interpret key events as shortcut bindings.
+/// Shortcut actions are suppressed until a key is captured, the recording is
+/// cancelled (Escape or `stop_record_key`), or the timeout elapses. Starting
+/// a new recording cancels the previous one.
+///
+/// # Arguments
+/// * `timeout_ms` - How long to wait for a key; defaults to 10 seconds and is
+///   capped at 60 seconds.
+/// * `sequence` - Whether to record two keys as a sequence binding.
+/// * `state` - Application state containing the hotkey table.
+///
+/// # Returns
+/// The captured binding, or `None` if the recording was cancelled or timed out.
 #[tauri::command]
-pub fn start_record_key() {
-    // Intentionally empty - keyboard capture happens via global rdev listener
+pub async fn start_record_key(
+    timeout_ms: Option<u64>,
+    sequence: Option<bool>,
+    state: tauri::State<'_, AppState>,
+) -> AppResult<Option<KeyBinding>> {
+    let timeout = timeout_ms
+        .map(Duration::from_millis)
+        .unwrap_or(hotkeys::DEFAULT_RECORD_TIMEOUT)
+        .min(hotkeys::MAX_RECORD_TIMEOUT);
+    let (id, receiver) = state.hotkeys.start_capture(sequence.unwrap_or(false));
+    let binding =
+        tauri::async_runtime::spawn_blocking(move || receiver.recv_timeout(timeout).ok().flatten())
+            .await
+            .unwrap_or(None);
+    state.hotkeys.finish_capture(id);
+    Ok(binding)
 }
 
-/// Signals the end of keyboard shortcut recording mode.
+/// Cancels the shortcut recording in progress, if any.
 ///
-/// Note: This is intentionally a no-op. See start_record_key for details.
+/// # Arguments
+/// * `state` - Application state containing the hotkey table.
+#[tauri::command]
+pub fn stop_record_key(state: tauri::State<AppState>) {
+    state.hotkeys.cancel_capture();
+}
+
+/// Matches a key pressed in the app window against the shortcuts.
+///
+/// Only used when the global listener is not running (see
+/// `get_hotkey_listener_error`); otherwise it already sees these keys.
+///
+/// # Arguments
+/// * `key` - The key, named like the browser's `KeyboardEvent.code`.
+/// * `app` - Handle used to fire the bound action.
+#[tauri::command]
+pub fn dispatch_key(mut key: KeyEventPayload, app: AppHandle) {
+    key.name = hotkeys::browser_code_to_rdev(&key.name).to_string();
+    hotkeys::dispatch(&app, key);
+}
+
+/// Passes the release of a key in the app window to the shortcut matcher.
+///
+/// # Arguments
+/// * `name` - The key, named like in `dispatch_key`.
+/// * `app` - Handle used to fire the bound action.
+#[tauri::command]
+pub fn dispatch_key_release(name: String, app: AppHandle) {
+    hotkeys::dispatch_release(&app, hotkeys::browser_code_to_rdev(&name));
+}
+
+/// Returns why the global shortcut listener stopped.
+///
+/// # Arguments
+/// * `state` - Application state containing the hotkey table.
+///
+/// # Returns
+/// The error, or `None` while the listener runs.
 #[tauri::command]
-pub fn stop_record_key() {
-    // Intentionally empty - serves as state signal only
+pub fn get_hotkey_listener_error(state: tauri::State<AppState>) -> Option<String> {
+    state.hotkeys.listener_error()
 }
 
 // ============================================================================
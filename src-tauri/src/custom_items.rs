@@ -0,0 +1,332 @@
+//! Custom items.
+//!
+//! Drops that are not in the built-in catalog (bases, jewels, rares, ...) are
+//! recorded as custom items, stored in the `custom_items` table of the run
+//! database. Runs reference them by ID like catalog items, so a custom item
+//! can be renamed, or merged into another one, without rewriting its name in
+//! every run.
+//!
+//! Before the table existed, custom drops were encoded in the drop ID itself
+//! as `custom:<name>:<quality>`; [`parse_legacy`] decodes those strings.
+//!
+//! The quality of every custom item is also kept in a [`QualityIndex`] in
+//! the application state, so [`crate::items::rarity_of`] can classify custom
+//! drops without a database query. Call [`QualityIndex::reload`] after
+//! changing the table.
+
+use crate::db::Database;
+use crate::error::{AppError, AppResult};
+use crate::items::RarityClass;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{RwLock, RwLockReadGuard};
+
+/// Prefix of custom item IDs.
+pub const CUSTOM_ITEM_PREFIX: &str = "custom-";
+/// Prefix of legacy custom drop IDs (`custom:<name>:<quality>`).
+pub const LEGACY_CUSTOM_PREFIX: &str = "custom:";
+
+/// Maximum length of a name or base type, in characters.
+const MAX_NAME_LEN: usize = 60;
+/// Maximum length of the notes, in characters.
+const MAX_NOTES_LEN: usize = 500;
+
+/// Quality of a custom item, serialized as the codes the frontend uses.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CustomQuality {
+    /// Normal or superior base.
+    #[default]
+    #[serde(rename = "1")]
+    Normal,
+    /// Magic item.
+    #[serde(rename = "2")]
+    Magic,
+    /// Rare item.
+    #[serde(rename = "3")]
+    Rare,
+}
+
+impl CustomQuality {
+    /// Returns the numeric code stored in the database.
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Normal => 1,
+            Self::Magic => 2,
+            Self::Rare => 3,
+        }
+    }
+
+    /// Parses a numeric code; unknown codes fall back to normal.
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            2 => Self::Magic,
+            3 => Self::Rare,
+            _ => Self::Normal,
+        }
+    }
+
+    /// Returns the rarity class drops of this quality are counted under.
+    pub fn rarity_class(self) -> RarityClass {
+        match self {
+            Self::Normal => RarityClass::Normal,
+            Self::Magic => RarityClass::Magic,
+            Self::Rare => RarityClass::Rare,
+        }
+    }
+}
+
+/// The user-editable part of a custom item.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CustomItemInfo {
+    /// Chinese item name.
+    #[serde(default)]
+    pub name_zh: String,
+    /// English item name.
+    #[serde(default)]
+    pub name_en: String,
+    /// Item quality.
+    #[serde(default)]
+    pub quality: CustomQuality,
+    /// Base item type, e.g. "Monarch" or "Grand Charm".
+    #[serde(default)]
+    pub base_type: Option<String>,
+    /// Free-form notes, e.g. the rolled stats.
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl CustomItemInfo {
+    /// Creates the info of an item known only by one name.
+    pub fn named(name: &str, quality: CustomQuality) -> Self {
+        Self {
+            name_zh: name.to_string(),
+            name_en: name.to_string(),
+            quality,
+            base_type: None,
+            notes: String::new(),
+        }
+    }
+
+    /// Trims the fields, fills a missing name from the other language and
+    /// checks the limits.
+    ///
+    /// # Returns
+    /// The cleaned-up info, or [`AppError::Validation`] describing the
+    /// first invalid field.
+    pub fn normalized(mut self) -> AppResult<Self> {
+        self.name_zh = self.name_zh.trim().to_string();
+        self.name_en = self.name_en.trim().to_string();
+        self.notes = self.notes.trim().to_string();
+        if self.name_zh.is_empty() && self.name_en.is_empty() {
+            return Err(AppError::Validation("item name is required".to_string()));
+        }
+        if self.name_zh.is_empty() {
+            self.name_zh = self.name_en.clone();
+        }
+        if self.name_en.is_empty() {
+            self.name_en = self.name_zh.clone();
+        }
+        self.base_type = self
+            .base_type
+            .map(|b| b.trim().to_string())
+            .filter(|b| !b.is_empty());
+
+        let too_long = [&self.name_zh, &self.name_en]
+            .into_iter()
+            .chain(self.base_type.as_ref())
+            .find(|s| s.chars().count() > MAX_NAME_LEN);
+        if let Some(name) = too_long {
+            return Err(AppError::Validation(format!(
+                "'{}' is longer than {} characters",
+                name, MAX_NAME_LEN
+            )));
+        }
+        if self.notes.chars().count() > MAX_NOTES_LEN {
+            return Err(AppError::Validation(format!(
+                "notes are longer than {} characters",
+                MAX_NOTES_LEN
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Returns whether two items have the same name (in either language,
+    /// ignoring case) and quality.
+    pub fn same_item(&self, other: &Self) -> bool {
+        self.quality == other.quality
+            && (self.name_zh.to_lowercase() == other.name_zh.to_lowercase()
+                || self.name_en.to_lowercase() == other.name_en.to_lowercase())
+    }
+}
+
+/// A custom item.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CustomItem {
+    /// Stable identifier, as stored in `RunRecord.drops`.
+    pub id: String,
+    #[serde(flatten)]
+    pub info: CustomItemInfo,
+    /// Unix timestamp (milliseconds) when the item was created.
+    pub created_at: i64,
+}
+
+impl CustomItem {
+    /// Creates a custom item with a new ID.
+    pub fn new(info: CustomItemInfo, created_at: i64) -> Self {
+        Self {
+            id: format!("{}{}", CUSTOM_ITEM_PREFIX, uuid::Uuid::new_v4()),
+            info,
+            created_at,
+        }
+    }
+}
+
+/// Decodes a legacy `custom:<name>:<quality>` drop ID.
+///
+/// The quality is taken from the last `:`-separated part only if it is a
+/// known code, so names containing colons survive. A missing or unknown
+/// quality is normal.
+///
+/// # Returns
+/// The name and quality, or `None` if `drop_id` is not a legacy custom drop
+/// or has an empty name.
+pub fn parse_legacy(drop_id: &str) -> Option<(String, CustomQuality)> {
+    let rest = drop_id.strip_prefix(LEGACY_CUSTOM_PREFIX)?;
+    let (name, quality) = match rest.rsplit_once(':') {
+        Some((name, code @ ("1" | "2" | "3"))) => {
+            (name, CustomQuality::from_code(code.parse().unwrap_or(1)))
+        }
+        _ => (rest, CustomQuality::Normal),
+    };
+    let name = name.trim();
+    (!name.is_empty()).then(|| (name.to_string(), quality))
+}
+
+/// Adds custom items defined by another install, e.g. uploaded with its
+/// cloud runs, so runs referring to them can be displayed here.
+///
+/// Items whose ID is already known are skipped. An item with the same name
+/// and quality as a local one is mapped to the local item instead of being
+/// added, and invalid definitions are ignored.
+///
+/// # Arguments
+/// * `db` - The run database.
+/// * `qualities` - The quality index, refreshed if items were added.
+/// * `items` - The foreign item definitions.
+///
+/// # Returns
+/// The local ID of each mapped item, by its foreign ID.
+pub fn adopt(
+    db: &Database,
+    qualities: &QualityIndex,
+    items: Vec<CustomItem>,
+) -> AppResult<HashMap<String, String>> {
+    let mut local = db.get_custom_items()?;
+    let mut mapped = HashMap::new();
+    let mut added = false;
+    for mut item in items {
+        if !item.id.starts_with(CUSTOM_ITEM_PREFIX) || local.iter().any(|l| l.id == item.id) {
+            continue;
+        }
+        let Ok(info) = item.info.normalized() else {
+            continue;
+        };
+        match local.iter().find(|l| l.info.same_item(&info)) {
+            Some(existing) => {
+                mapped.insert(item.id, existing.id.clone());
+            }
+            None => {
+                item.info = info;
+                db.write_custom_item(&item)?;
+                local.push(item);
+                added = true;
+            }
+        }
+    }
+    if added {
+        qualities.reload(db)?;
+    }
+    Ok(mapped)
+}
+
+// ============================================================================
+// Quality Index
+// ============================================================================
+
+/// Quality of every custom item, by ID.
+pub type Qualities = HashMap<String, CustomQuality>;
+
+/// In-memory copy of the custom item qualities.
+#[derive(Default)]
+pub struct QualityIndex {
+    qualities: RwLock<Qualities>,
+}
+
+impl QualityIndex {
+    /// Loads the quality of every custom item from the database.
+    pub fn load(db: &Database) -> AppResult<Self> {
+        let index = Self::default();
+        index.reload(db)?;
+        Ok(index)
+    }
+
+    /// Refreshes the index from the database.
+    pub fn reload(&self, db: &Database) -> AppResult<()> {
+        let qualities = db
+            .get_custom_items()?
+            .into_iter()
+            .map(|item| (item.id, item.info.quality))
+            .collect();
+        *self.qualities.write().unwrap_or_else(|e| e.into_inner()) = qualities;
+        Ok(())
+    }
+
+    /// Returns the qualities, by custom item ID.
+    pub fn read(&self) -> RwLockReadGuard<'_, Qualities> {
+        self.qualities.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_ids_are_parsed() {
+        assert_eq!(
+            parse_legacy("custom:Jewel:2"),
+            Some(("Jewel".to_string(), CustomQuality::Magic))
+        );
+        assert_eq!(
+            parse_legacy("custom:Monarch"),
+            Some(("Monarch".to_string(), CustomQuality::Normal))
+        );
+        // Only a known quality code is split off
+        assert_eq!(
+            parse_legacy("custom:Rare: Amulet:3"),
+            Some(("Rare: Amulet".to_string(), CustomQuality::Rare))
+        );
+        assert_eq!(
+            parse_legacy("custom:Ratio 1:4"),
+            Some(("Ratio 1:4".to_string(), CustomQuality::Normal))
+        );
+        assert_eq!(parse_legacy("custom: :1"), None);
+        assert_eq!(parse_legacy("r30"), None);
+    }
+
+    #[test]
+    fn custom_item_info_is_normalized_and_validated() {
+        let mut info = CustomItemInfo::named("", CustomQuality::Rare);
+        info.name_en = " Grand Charm ".to_string();
+        info.base_type = Some("  ".to_string());
+        let info = info.normalized().unwrap();
+        assert_eq!(info.name_zh, "Grand Charm");
+        assert_eq!(info.base_type, None);
+
+        assert!(CustomItemInfo::named(" ", CustomQuality::Normal)
+            .normalized()
+            .is_err());
+        assert!(info.same_item(&CustomItemInfo::named("grand charm", CustomQuality::Rare)));
+        assert!(!info.same_item(&CustomItemInfo::named("Grand Charm", CustomQuality::Magic)));
+    }
+}
@@ -0,0 +1,917 @@
+//! SQLite storage backend for run records.
+//!
+//! Runs are stored in an embedded SQLite database (`runs.db`) inside the
+//! application data directory. Inserting or deleting a single run only touches
+//! that row, instead of re-serializing the whole history like the legacy
+//! `runs.json` store did.
+//!
+//! On first launch the legacy `runs.json` and `runs_cloud.json` files are
+//! imported into the database. The original files are left untouched on disk,
+//! except for corrupted ones, which are moved aside rather than imported as
+//! empty. The import of a corrupted file is not marked done, so a repaired
+//! file put back in place is imported on the next launch.
+//!
+//! The database runs with `synchronous = FULL`, so a committed write survives
+//! a crash or power loss. A database that fails its integrity check on open is
+//! moved aside and rebuilt from the legacy files.
+
+use crate::custom_items::{CustomItem, CustomItemInfo, CustomQuality};
+use crate::error::{AppError, AppResult};
+use crate::migrations::{migrate_legacy_run, migrate_runs_db, upgrade_imported_runs};
+use crate::models::{RunRecord, Session, TrashedRun};
+use crate::scenes::{Scene, SceneInfo};
+use crate::utils::{
+    get_cloud_runs_path, get_db_path, get_runs_path, load_json, quarantine_file, write_atomic,
+};
+use rusqlite::{params, Connection, ErrorCode, OptionalExtension, Row, Transaction};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+/// Table holding local run records.
+const RUNS_TABLE: &str = "runs";
+/// Table holding the local cache of cloud run records.
+const CLOUD_RUNS_TABLE: &str = "cloud_runs";
+/// Table holding deleted runs until they are restored or purged.
+const TRASH_TABLE: &str = "trash";
+/// Meta key set once the legacy JSON files have been imported.
+const LEGACY_IMPORT_KEY: &str = "legacy_json_imported";
+
+/// Column list shared by every run query, in `row_to_run` order.
+const RUN_COLUMNS: &str = "id, timestamp, date_str, scene_id, duration_ms, drops, is_tz, edits, \
+                           session_id, timeline, segments";
+/// Column list of the sessions table, in `row_to_session` order.
+const SESSION_COLUMNS: &str = "id, scene_id, started_at, ended_at, is_tz, notes";
+/// Column list of the custom scenes table, in `row_to_scene` order.
+const SCENE_COLUMNS: &str = "id, name_zh, name_en, label_zh, label_en, act, area, segments";
+/// Column list of the custom items table, in `row_to_custom_item` order.
+const CUSTOM_ITEM_COLUMNS: &str = "id, name_zh, name_en, quality, base_type, notes, created_at";
+/// Number of columns in [`RUN_COLUMNS`].
+const RUN_COLUMN_COUNT: usize = 11;
+
+/// Handle to the run database.
+///
+/// The connection is guarded by a mutex so the handle can live in
+/// [`AppState`](crate::utils::AppState) and be shared across commands.
+pub struct Database {
+    conn: Mutex<Connection>,
+    /// Why a legacy JSON file could not be imported on open, if it could not.
+    legacy_import_error: Option<String>,
+}
+
+impl Database {
+    /// Opens (or creates) the run database in the given data directory.
+    ///
+    /// Migrates the schema to the current version and imports the legacy JSON files
+    /// the first time the database is opened.
+    ///
+    /// # Arguments
+    /// * `dir` - The application data directory path.
+    pub fn open(dir: &Path) -> AppResult<Self> {
+        let db_path = get_db_path(dir);
+        let mut conn = match open_checked(&db_path) {
+            Ok(conn) => conn,
+            Err(e) if is_corruption(&e) => {
+                // Keep the damaged database for manual recovery and start fresh
+                quarantine_db(&db_path);
+                open_checked(&db_path)?
+            }
+            Err(e) => return Err(e.into()),
+        };
+        migrate_runs_db(&mut conn)?;
+        let legacy_import_error = import_legacy_json(&mut conn, dir)?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+            legacy_import_error,
+        })
+    }
+
+    /// Opens an empty database in memory, for tests.
+    #[cfg(test)]
+    pub(crate) fn open_in_memory() -> AppResult<Self> {
+        let mut conn = Connection::open_in_memory()?;
+        migrate_runs_db(&mut conn)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            legacy_import_error: None,
+        })
+    }
+
+    /// Returns why a legacy JSON file could not be imported when the
+    /// database was opened, or `None` if nothing failed.
+    pub fn legacy_import_error(&self) -> Option<&str> {
+        self.legacy_import_error.as_deref()
+    }
+
+    /// Locks the connection, recovering it if a previous holder panicked.
+    fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns all local runs, in insertion order.
+    ///
+    /// Used once at startup to fill the in-memory [`RunCache`](crate::cache::RunCache).
+    pub fn load_runs(&self) -> rusqlite::Result<Vec<RunRecord>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {RUN_COLUMNS} FROM {RUNS_TABLE} ORDER BY rowid"
+        ))?;
+        let rows = stmt.query_map([], row_to_run)?;
+        rows.collect()
+    }
+
+    /// Inserts a run, replacing any existing run with the same ID.
+    pub fn insert_run(&self, run: &RunRecord) -> rusqlite::Result<()> {
+        insert_run(&self.conn(), RUNS_TABLE, run, "REPLACE")
+    }
+
+    /// Overwrites saved runs in place, keeping their position in insertion order.
+    ///
+    /// All runs are written in a single transaction.
+    pub fn update_runs(&self, runs: &[RunRecord]) -> rusqlite::Result<()> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        for run in runs {
+            update_run(&tx, run)?;
+        }
+        tx.commit()
+    }
+
+    /// Moves runs to the trash in a single transaction.
+    ///
+    /// # Arguments
+    /// * `ids` - The IDs of the runs to delete.
+    /// * `deleted_at` - Unix timestamp (milliseconds) recorded in the trash.
+    ///
+    /// # Returns
+    /// The number of runs moved.
+    pub fn trash_runs(&self, ids: &[&str], deleted_at: i64) -> rusqlite::Result<usize> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        let mut deleted = 0;
+        for id in ids {
+            tx.prepare_cached(&format!(
+                "INSERT OR REPLACE INTO {TRASH_TABLE} ({RUN_COLUMNS}, deleted_at) \
+                 SELECT {RUN_COLUMNS}, ?2 FROM {RUNS_TABLE} WHERE id = ?1"
+            ))?
+            .execute(params![id, deleted_at])?;
+            deleted += tx
+                .prepare_cached("DELETE FROM runs WHERE id = ?1")?
+                .execute(params![id])?;
+        }
+        tx.commit()?;
+        Ok(deleted)
+    }
+
+    /// Moves every local run to the trash.
+    ///
+    /// # Arguments
+    /// * `deleted_at` - Unix timestamp (milliseconds) recorded in the trash.
+    pub fn trash_all_runs(&self, deleted_at: i64) -> rusqlite::Result<()> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        tx.execute(
+            &format!(
+                "INSERT OR REPLACE INTO {TRASH_TABLE} ({RUN_COLUMNS}, deleted_at) \
+                 SELECT {RUN_COLUMNS}, ?1 FROM {RUNS_TABLE} ORDER BY rowid"
+            ),
+            params![deleted_at],
+        )?;
+        tx.execute("DELETE FROM runs", [])?;
+        tx.commit()
+    }
+
+    /// Returns the runs in the trash, most recently deleted first.
+    pub fn get_trash(&self) -> rusqlite::Result<Vec<TrashedRun>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {RUN_COLUMNS}, deleted_at FROM {TRASH_TABLE} \
+             ORDER BY deleted_at DESC, rowid"
+        ))?;
+        let rows = stmt.query_map([], |row| {
+            Ok(TrashedRun {
+                run: row_to_run(row)?,
+                deleted_at: row.get(RUN_COLUMN_COUNT)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    /// Moves a run from the trash back into the local runs.
+    ///
+    /// # Returns
+    /// The restored run, or `None` if the trash has no run with this ID.
+    pub fn restore_run(&self, id: &str) -> rusqlite::Result<Option<RunRecord>> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        let run = tx
+            .query_row(
+                &format!("SELECT {RUN_COLUMNS} FROM {TRASH_TABLE} WHERE id = ?1"),
+                params![id],
+                row_to_run,
+            )
+            .optional()?;
+        if let Some(ref run) = run {
+            insert_run(&tx, RUNS_TABLE, run, "REPLACE")?;
+            tx.execute("DELETE FROM trash WHERE id = ?1", params![id])?;
+        }
+        tx.commit()?;
+        Ok(run)
+    }
+
+    /// Permanently deletes every run in the trash.
+    ///
+    /// # Returns
+    /// The number of runs deleted.
+    pub fn empty_trash(&self) -> rusqlite::Result<usize> {
+        self.conn().execute("DELETE FROM trash", [])
+    }
+
+    /// Permanently deletes trashed runs deleted before the given time.
+    ///
+    /// # Arguments
+    /// * `cutoff` - Unix timestamp (milliseconds); older entries are purged.
+    ///
+    /// # Returns
+    /// The number of runs deleted.
+    pub fn purge_trash(&self, cutoff: i64) -> rusqlite::Result<usize> {
+        self.conn()
+            .execute("DELETE FROM trash WHERE deleted_at < ?1", params![cutoff])
+    }
+
+    /// Returns all sessions, most recently started first.
+    pub fn get_sessions(&self) -> rusqlite::Result<Vec<Session>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SESSION_COLUMNS} FROM sessions ORDER BY started_at DESC"
+        ))?;
+        let rows = stmt.query_map([], row_to_session)?;
+        rows.collect()
+    }
+
+    /// Returns a session by ID.
+    pub fn get_session(&self, id: &str) -> rusqlite::Result<Option<Session>> {
+        self.conn()
+            .query_row(
+                &format!("SELECT {SESSION_COLUMNS} FROM sessions WHERE id = ?1"),
+                params![id],
+                row_to_session,
+            )
+            .optional()
+    }
+
+    /// Inserts a new session, first ending any session still open.
+    ///
+    /// A session left open (e.g. the app was closed mid-session) ends when
+    /// its last run ended, or when it started if it has no runs.
+    pub fn start_session(&self, session: &Session) -> rusqlite::Result<()> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "UPDATE sessions SET ended_at = COALESCE(
+                 (SELECT MAX(timestamp + duration_ms) FROM runs WHERE runs.session_id = sessions.id),
+                 started_at)
+             WHERE ended_at IS NULL",
+            [],
+        )?;
+        write_session(&tx, session)?;
+        tx.commit()
+    }
+
+    /// Overwrites a saved session.
+    pub fn update_session(&self, session: &Session) -> rusqlite::Result<()> {
+        write_session(&self.conn(), session)
+    }
+
+    /// Deletes a session and moves its runs to the trash, in one transaction.
+    ///
+    /// # Arguments
+    /// * `id` - The ID of the session to delete.
+    /// * `deleted_at` - Unix timestamp (milliseconds) recorded in the trash.
+    ///
+    /// # Returns
+    /// The number of runs moved to the trash.
+    pub fn delete_session(&self, id: &str, deleted_at: i64) -> rusqlite::Result<usize> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        tx.execute(
+            &format!(
+                "INSERT OR REPLACE INTO {TRASH_TABLE} ({RUN_COLUMNS}, deleted_at) \
+                 SELECT {RUN_COLUMNS}, ?2 FROM {RUNS_TABLE} WHERE session_id = ?1 ORDER BY rowid"
+            ),
+            params![id, deleted_at],
+        )?;
+        let deleted = tx.execute("DELETE FROM runs WHERE session_id = ?1", params![id])?;
+        tx.execute("DELETE FROM sessions WHERE id = ?1", params![id])?;
+        tx.commit()?;
+        Ok(deleted)
+    }
+
+    /// Moves every run of one session into another and deletes the source, in one transaction.
+    ///
+    /// Trashed runs of the source are moved too, so restoring them lands in the target.
+    ///
+    /// # Arguments
+    /// * `source_id` - The session to merge away.
+    /// * `target` - The merged session, already combined by the caller.
+    ///
+    /// # Returns
+    /// The number of local runs moved.
+    pub fn merge_sessions(&self, source_id: &str, target: &Session) -> rusqlite::Result<usize> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        let moved = tx.execute(
+            "UPDATE runs SET session_id = ?2 WHERE session_id = ?1",
+            params![source_id, target.id],
+        )?;
+        tx.execute(
+            "UPDATE trash SET session_id = ?2 WHERE session_id = ?1",
+            params![source_id, target.id],
+        )?;
+        write_session(&tx, target)?;
+        tx.execute("DELETE FROM sessions WHERE id = ?1", params![source_id])?;
+        tx.commit()?;
+        Ok(moved)
+    }
+
+    /// Returns all custom scenes, ordered by English name.
+    pub fn get_scenes(&self) -> rusqlite::Result<Vec<Scene>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SCENE_COLUMNS} FROM scenes ORDER BY name_en COLLATE NOCASE, id"
+        ))?;
+        let rows = stmt.query_map([], row_to_scene)?;
+        rows.collect()
+    }
+
+    /// Returns a custom scene by ID.
+    pub fn get_scene(&self, id: &str) -> rusqlite::Result<Option<Scene>> {
+        self.conn()
+            .query_row(
+                &format!("SELECT {SCENE_COLUMNS} FROM scenes WHERE id = ?1"),
+                params![id],
+                row_to_scene,
+            )
+            .optional()
+    }
+
+    /// Inserts or overwrites a custom scene.
+    pub fn write_scene(&self, scene: &Scene) -> rusqlite::Result<()> {
+        let info = &scene.info;
+        self.conn()
+            .prepare_cached(&format!(
+                "INSERT OR REPLACE INTO scenes ({SCENE_COLUMNS}) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"
+            ))?
+            .execute(params![
+                scene.id,
+                info.name_zh,
+                info.name_en,
+                info.label_zh,
+                info.label_en,
+                info.act,
+                info.area,
+                to_json_text(&info.segments)?
+            ])?;
+        Ok(())
+    }
+
+    /// Deletes a custom scene.
+    ///
+    /// # Returns
+    /// `true` if a scene was deleted.
+    pub fn delete_scene(&self, id: &str) -> rusqlite::Result<bool> {
+        Ok(self
+            .conn()
+            .execute("DELETE FROM scenes WHERE id = ?1", params![id])?
+            > 0)
+    }
+
+    /// Returns all custom items, ordered by English name.
+    pub fn get_custom_items(&self) -> rusqlite::Result<Vec<CustomItem>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {CUSTOM_ITEM_COLUMNS} FROM custom_items ORDER BY name_en COLLATE NOCASE, id"
+        ))?;
+        let rows = stmt.query_map([], row_to_custom_item)?;
+        rows.collect()
+    }
+
+    /// Returns a custom item by ID.
+    pub fn get_custom_item(&self, id: &str) -> rusqlite::Result<Option<CustomItem>> {
+        self.conn()
+            .query_row(
+                &format!("SELECT {CUSTOM_ITEM_COLUMNS} FROM custom_items WHERE id = ?1"),
+                params![id],
+                row_to_custom_item,
+            )
+            .optional()
+    }
+
+    /// Inserts or overwrites a custom item.
+    pub fn write_custom_item(&self, item: &CustomItem) -> rusqlite::Result<()> {
+        write_custom_item(&self.conn(), item)
+    }
+
+    /// Deletes a custom item.
+    ///
+    /// # Returns
+    /// `true` if an item was deleted.
+    pub fn delete_custom_item(&self, id: &str) -> rusqlite::Result<bool> {
+        Ok(self
+            .conn()
+            .execute("DELETE FROM custom_items WHERE id = ?1", params![id])?
+            > 0)
+    }
+
+    /// Counts the local, trashed and cached cloud runs and the sessions
+    /// recorded in a scene.
+    pub fn count_scene_uses(&self, id: &str) -> rusqlite::Result<usize> {
+        let conn = self.conn();
+        let mut count = 0;
+        for table in [RUNS_TABLE, TRASH_TABLE, CLOUD_RUNS_TABLE, "sessions"] {
+            count += conn.query_row(
+                &format!("SELECT count(*) FROM {table} WHERE scene_id = ?1"),
+                params![id],
+                |row| row.get::<_, usize>(0),
+            )?;
+        }
+        Ok(count)
+    }
+
+    /// Counts the local, trashed and cached cloud runs that refer to a custom
+    /// item in their drops, timeline or edit history.
+    pub fn count_custom_item_runs(&self, id: &str) -> rusqlite::Result<usize> {
+        let needle = to_json_text(&id)?;
+        let conn = self.conn();
+        let mut count = 0;
+        for table in [RUNS_TABLE, TRASH_TABLE, CLOUD_RUNS_TABLE] {
+            count += conn.query_row(
+                &format!(
+                    "SELECT count(*) FROM {table} WHERE instr(drops || timeline || edits, ?1) > 0"
+                ),
+                params![needle],
+                |row| row.get::<_, usize>(0),
+            )?;
+        }
+        Ok(count)
+    }
+
+    /// Points every reference to one custom item at another and deletes the
+    /// source, in one transaction.
+    ///
+    /// Drops, timeline events and edit history of local, trashed and cached
+    /// cloud runs are rewritten, so restoring a run from the trash refers to
+    /// the target.
+    ///
+    /// # Arguments
+    /// * `source_id` - The item to merge away.
+    /// * `target_id` - The item to keep.
+    ///
+    /// # Returns
+    /// The number of local runs changed.
+    pub fn merge_custom_items(&self, source_id: &str, target_id: &str) -> rusqlite::Result<usize> {
+        // IDs are matched as quoted JSON strings, so no other value can contain them
+        let from = to_json_text(&source_id)?;
+        let to = to_json_text(&target_id)?;
+
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        let mut changed = 0;
+        for table in [RUNS_TABLE, TRASH_TABLE, CLOUD_RUNS_TABLE] {
+            let rows = tx.execute(
+                &format!(
+                    "UPDATE {table} SET drops = replace(drops, ?1, ?2), \
+                     timeline = replace(timeline, ?1, ?2), edits = replace(edits, ?1, ?2) \
+                     WHERE instr(drops, ?1) > 0 OR instr(timeline, ?1) > 0 \
+                     OR instr(edits, ?1) > 0"
+                ),
+                params![from, to],
+            )?;
+            if table == RUNS_TABLE {
+                changed = rows;
+            }
+        }
+        tx.execute("DELETE FROM custom_items WHERE id = ?1", params![source_id])?;
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// Returns the cached cloud runs, in the order they were saved.
+    pub fn get_cloud_runs(&self) -> rusqlite::Result<Vec<RunRecord>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {RUN_COLUMNS} FROM {CLOUD_RUNS_TABLE} ORDER BY rowid"
+        ))?;
+        let rows = stmt.query_map([], row_to_run)?;
+        rows.collect()
+    }
+
+    /// Replaces the cloud run cache with the given runs.
+    pub fn replace_cloud_runs(&self, runs: &[RunRecord]) -> rusqlite::Result<()> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM cloud_runs", [])?;
+        for run in runs {
+            insert_run(&tx, CLOUD_RUNS_TABLE, run, "REPLACE")?;
+        }
+        tx.commit()
+    }
+}
+
+/// Opens a connection and verifies the database file is intact.
+fn open_checked(path: &Path) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(path)?;
+    conn.pragma_update(None, "journal_mode", "WAL")?;
+    conn.pragma_update(None, "synchronous", "FULL")?;
+
+    let status: String = conn.query_row("PRAGMA quick_check", [], |row| row.get(0))?;
+    if status != "ok" {
+        return Err(rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CORRUPT),
+            Some(status),
+        ));
+    }
+    Ok(conn)
+}
+
+/// Returns whether an error means the database file itself is damaged.
+fn is_corruption(e: &rusqlite::Error) -> bool {
+    matches!(
+        e.sqlite_error_code(),
+        Some(ErrorCode::DatabaseCorrupt | ErrorCode::NotADatabase)
+    )
+}
+
+/// Moves a corrupted database and its WAL side files out of the way.
+///
+/// The side files keep their suffix next to the renamed database, so the
+/// quarantined copy can still be opened as-is for manual recovery.
+fn quarantine_db(path: &Path) {
+    let Ok(target) = quarantine_file(path) else {
+        return;
+    };
+    for suffix in ["-wal", "-shm"] {
+        let side = with_suffix(path, suffix);
+        if side.exists() {
+            let _ = fs::rename(side, with_suffix(&target, suffix));
+        }
+    }
+}
+
+/// Appends a suffix to the file name of a path.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+// ============================================================================
+// Row Helpers
+// ============================================================================
+
+/// Inserts a run into the given table.
+///
+/// `on_conflict` is the SQLite conflict resolution (`REPLACE` or `IGNORE`)
+/// applied when a run with the same ID already exists.
+fn insert_run(
+    conn: &Connection,
+    table: &str,
+    run: &RunRecord,
+    on_conflict: &str,
+) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare_cached(&format!(
+        "INSERT OR {on_conflict} INTO {table} ({RUN_COLUMNS}) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)"
+    ))?;
+    stmt.execute(params![
+        run.id,
+        run.timestamp,
+        run.date_str,
+        run.scene_id,
+        run.duration_ms,
+        to_json_text(&run.drops)?,
+        run.is_tz,
+        to_json_text(&run.edits)?,
+        run.session_id,
+        to_json_text(&run.timeline)?,
+        to_json_text(&run.segments)?
+    ])?;
+    Ok(())
+}
+
+/// Overwrites the mutable columns of a saved run.
+fn update_run(conn: &Connection, run: &RunRecord) -> rusqlite::Result<()> {
+    conn.prepare_cached(
+        "UPDATE runs SET timestamp = ?2, date_str = ?3, scene_id = ?4, duration_ms = ?5, \
+         drops = ?6, is_tz = ?7, edits = ?8, session_id = ?9, timeline = ?10, \
+         segments = ?11 WHERE id = ?1",
+    )?
+    .execute(params![
+        run.id,
+        run.timestamp,
+        run.date_str,
+        run.scene_id,
+        run.duration_ms,
+        to_json_text(&run.drops)?,
+        run.is_tz,
+        to_json_text(&run.edits)?,
+        run.session_id,
+        to_json_text(&run.timeline)?,
+        to_json_text(&run.segments)?
+    ])?;
+    Ok(())
+}
+
+/// Maps a row selected with [`RUN_COLUMNS`] to a run record.
+fn row_to_run(row: &Row) -> rusqlite::Result<RunRecord> {
+    Ok(RunRecord {
+        id: row.get(0)?,
+        timestamp: row.get(1)?,
+        date_str: row.get(2)?,
+        scene_id: row.get(3)?,
+        duration_ms: row.get(4)?,
+        drops: json_column(row, 5)?,
+        is_tz: row.get(6)?,
+        edits: json_column(row, 7)?,
+        session_id: row.get(8)?,
+        timeline: json_column(row, 9)?,
+        segments: json_column(row, 10)?,
+    })
+}
+
+/// Inserts or overwrites a session.
+fn write_session(conn: &Connection, session: &Session) -> rusqlite::Result<()> {
+    conn.prepare_cached(&format!(
+        "INSERT OR REPLACE INTO sessions ({SESSION_COLUMNS}) VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+    ))?
+    .execute(params![
+        session.id,
+        session.scene_id,
+        session.started_at,
+        session.ended_at,
+        session.is_tz,
+        session.notes
+    ])?;
+    Ok(())
+}
+
+/// Maps a row selected with [`SESSION_COLUMNS`] to a session.
+fn row_to_session(row: &Row) -> rusqlite::Result<Session> {
+    Ok(Session {
+        id: row.get(0)?,
+        scene_id: row.get(1)?,
+        started_at: row.get(2)?,
+        ended_at: row.get(3)?,
+        is_tz: row.get(4)?,
+        notes: row.get(5)?,
+    })
+}
+
+/// Maps a row selected with [`SCENE_COLUMNS`] to a custom scene.
+fn row_to_scene(row: &Row) -> rusqlite::Result<Scene> {
+    Ok(Scene {
+        id: row.get(0)?,
+        custom: true,
+        info: SceneInfo {
+            name_zh: row.get(1)?,
+            name_en: row.get(2)?,
+            label_zh: row.get(3)?,
+            label_en: row.get(4)?,
+            act: row.get(5)?,
+            area: row.get(6)?,
+            segments: json_column(row, 7)?,
+        },
+    })
+}
+
+/// Inserts or overwrites a custom item.
+fn write_custom_item(conn: &Connection, item: &CustomItem) -> rusqlite::Result<()> {
+    let info = &item.info;
+    conn.prepare_cached(&format!(
+        "INSERT OR REPLACE INTO custom_items ({CUSTOM_ITEM_COLUMNS}) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"
+    ))?
+    .execute(params![
+        item.id,
+        info.name_zh,
+        info.name_en,
+        info.quality.code(),
+        info.base_type,
+        info.notes,
+        item.created_at
+    ])?;
+    Ok(())
+}
+
+/// Maps a row selected with [`CUSTOM_ITEM_COLUMNS`] to a custom item.
+fn row_to_custom_item(row: &Row) -> rusqlite::Result<CustomItem> {
+    Ok(CustomItem {
+        id: row.get(0)?,
+        info: CustomItemInfo {
+            name_zh: row.get(1)?,
+            name_en: row.get(2)?,
+            quality: CustomQuality::from_code(row.get(3)?),
+            base_type: row.get(4)?,
+            notes: row.get(5)?,
+        },
+        created_at: row.get(6)?,
+    })
+}
+
+/// Serializes a value for storage in a JSON text column.
+fn to_json_text<T: Serialize>(value: &T) -> rusqlite::Result<String> {
+    serde_json::to_string(value).map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+}
+
+/// Reads and parses a JSON text column.
+fn json_column<T: DeserializeOwned>(row: &Row, idx: usize) -> rusqlite::Result<T> {
+    let text: String = row.get(idx)?;
+    serde_json::from_str(&text).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(idx, rusqlite::types::Type::Text, Box::new(e))
+    })
+}
+
+// ============================================================================
+// Legacy Import
+// ============================================================================
+
+/// Imports `runs.json` and `runs_cloud.json` into the database, once.
+///
+/// Runs whose ID already exists in the database are skipped. Imported runs
+/// get the same scene and custom item ID upgrades as migrated ones.
+///
+/// Each file is marked imported on its own, so a file that was imported is
+/// not imported again (bringing back runs deleted since) while another one
+/// is retried. The import as a whole is only marked done once no file was
+/// corrupted.
+///
+/// # Returns
+/// A description of the corrupted files, or `None` if every file was imported.
+fn import_legacy_json(conn: &mut Connection, dir: &Path) -> AppResult<Option<String>> {
+    if meta_value(conn, LEGACY_IMPORT_KEY)?.is_some() {
+        return Ok(None);
+    }
+
+    let tx = conn.transaction()?;
+    let mut failures = Vec::new();
+    for (table, path) in [
+        (RUNS_TABLE, get_runs_path(dir)),
+        (CLOUD_RUNS_TABLE, get_cloud_runs_path(dir)),
+    ] {
+        let key = format!("{}:{}", LEGACY_IMPORT_KEY, table);
+        if meta_value(&tx, &key)?.is_some() {
+            continue;
+        }
+        match import_legacy_file(&tx, table, &path) {
+            Ok(()) => set_meta_value(&tx, &key)?,
+            Err(AppError::Corrupted(message)) => failures.push(message),
+            Err(e) => return Err(e),
+        }
+    }
+    upgrade_imported_runs(&tx)?;
+    if failures.is_empty() {
+        set_meta_value(&tx, LEGACY_IMPORT_KEY)?;
+    }
+    tx.commit()?;
+    Ok((!failures.is_empty()).then(|| failures.join("; ")))
+}
+
+/// Returns the value of a key in the `meta` table.
+fn meta_value(conn: &Connection, key: &str) -> rusqlite::Result<Option<String>> {
+    conn.query_row(
+        "SELECT value FROM meta WHERE key = ?1",
+        params![key],
+        |row| row.get(0),
+    )
+    .optional()
+}
+
+/// Marks a key in the `meta` table as done now.
+fn set_meta_value(conn: &Connection, key: &str) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO meta (key, value) VALUES (?1, ?2)",
+        params![key, chrono::Utc::now().to_rfc3339()],
+    )?;
+    Ok(())
+}
+
+/// Copies the runs of a single legacy JSON file into a table.
+///
+/// Records are upgraded with [`migrate_legacy_run`] first. Records that cannot
+/// be read are written to `<name>.rejected-<timestamp>` next to the file for
+/// manual recovery, and the rest are imported. A corrupted file is moved aside
+/// and reported as [`AppError::Corrupted`]; any other read error aborts the
+/// import so it is retried next launch.
+fn import_legacy_file(tx: &Transaction, table: &str, path: &Path) -> AppResult<()> {
+    let values: Vec<Value> = load_json(path)?.unwrap_or_default();
+
+    let mut rejected = Vec::new();
+    for value in values {
+        let run = migrate_legacy_run(value.clone())
+            .and_then(|v| Ok(serde_json::from_value::<RunRecord>(v)?));
+        match run {
+            Ok(run) => insert_run(tx, table, &run, "IGNORE")?,
+            Err(_) => rejected.push(value),
+        }
+    }
+
+    if !rejected.is_empty() {
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S%3f");
+        let rejected_path = path.with_file_name(format!("{}.rejected-{}", file_name, stamp));
+        write_atomic(&rejected_path, serde_json::to_string_pretty(&rejected)?)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::test_run;
+
+    fn ids(runs: &[RunRecord]) -> Vec<&str> {
+        runs.iter().map(|r| r.id.as_str()).collect()
+    }
+
+    /// A database holding runs `a` to `d`.
+    fn setup() -> Database {
+        let db = Database::open_in_memory().unwrap();
+        for id in ["a", "b", "c", "d"] {
+            let mut run = test_run("pit", "2024-06-01", 60_000, &["ber"], false);
+            run.id = id.to_string();
+            db.insert_run(&run).unwrap();
+        }
+        db
+    }
+
+    #[test]
+    fn trashed_runs_can_be_restored_until_purged() {
+        let db = setup();
+        assert_eq!(db.trash_runs(&["a", "missing"], 1_000).unwrap(), 1);
+        assert_eq!(db.trash_runs(&["b", "c"], 2_000).unwrap(), 2);
+        assert_eq!(ids(&db.load_runs().unwrap()), ["d"]);
+
+        let trash = db.get_trash().unwrap();
+        let trashed: Vec<(&str, i64)> = trash
+            .iter()
+            .map(|t| (t.run.id.as_str(), t.deleted_at))
+            .collect();
+        assert_eq!(trashed, [("b", 2_000), ("c", 2_000), ("a", 1_000)]);
+        assert_eq!(trash[0].run.drops, ["ber"]);
+
+        let restored = db.restore_run("b").unwrap().unwrap();
+        assert_eq!(restored.drops, ["ber"]);
+        assert!(db.restore_run("b").unwrap().is_none());
+        assert_eq!(ids(&db.load_runs().unwrap()), ["d", "b"]);
+
+        // Only entries deleted before the cutoff are purged
+        assert_eq!(db.purge_trash(1_500).unwrap(), 1);
+        assert!(db.restore_run("a").unwrap().is_none());
+        assert_eq!(db.purge_trash(2_000).unwrap(), 0);
+        assert_eq!(ids(&db.load_runs().unwrap()), ["d", "b"]);
+
+        db.trash_all_runs(3_000).unwrap();
+        assert!(db.load_runs().unwrap().is_empty());
+        assert_eq!(db.empty_trash().unwrap(), 3);
+        assert!(db.get_trash().unwrap().is_empty());
+    }
+
+    #[test]
+    fn corrupted_legacy_files_are_reported_and_not_marked_imported() {
+        let dir = std::env::temp_dir().join(format!("d2run-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(get_runs_path(&dir), "[{\"id\": ").unwrap();
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate_runs_db(&mut conn).unwrap();
+
+        let error = import_legacy_json(&mut conn, &dir).unwrap().unwrap();
+        assert!(error.contains("runs.json"));
+        assert!(meta_value(&conn, LEGACY_IMPORT_KEY).unwrap().is_none());
+        let cloud_key = format!("{}:{}", LEGACY_IMPORT_KEY, CLOUD_RUNS_TABLE);
+        assert!(meta_value(&conn, &cloud_key).unwrap().is_some());
+
+        // The repaired file is imported on the next launch
+        fs::write(
+            get_runs_path(&dir),
+            serde_json::to_string(&[test_run("pit", "2024-06-01", 60_000, &[], false)]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(import_legacy_json(&mut conn, &dir).unwrap(), None);
+        assert!(meta_value(&conn, LEGACY_IMPORT_KEY).unwrap().is_some());
+        assert_eq!(
+            conn.query_row("SELECT count(*) FROM runs", [], |row| row
+                .get::<_, usize>(0))
+                .unwrap(),
+            1
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
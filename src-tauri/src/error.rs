@@ -0,0 +1,65 @@
+//! Error types returned by storage and run commands.
+//!
+//! Errors are serialized to the frontend as `{ kind, message }` objects, so the
+//! UI can tell a failed save apart from an empty history.
+
+use serde::Serialize;
+use std::fmt;
+use std::io;
+
+/// An error raised while reading or writing persisted data.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", content = "message")]
+pub enum AppError {
+    /// A file could not be read or written (e.g. disk full, permission denied).
+    Io(String),
+    /// The run database returned an error.
+    Database(String),
+    /// A stored file could not be parsed and was moved aside.
+    Corrupted(String),
+    /// Data could not be converted to or from JSON.
+    Serialization(String),
+    /// A stored file was written by a newer version of the app.
+    UnsupportedVersion(String),
+    /// The requested record does not exist.
+    NotFound(String),
+    /// The request contained invalid values.
+    Validation(String),
+}
+
+/// Result type used by storage commands.
+pub type AppResult<T> = Result<T, AppError>;
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Io(msg) => write!(f, "file error: {}", msg),
+            AppError::Database(msg) => write!(f, "database error: {}", msg),
+            AppError::Corrupted(msg) => write!(f, "corrupted data: {}", msg),
+            AppError::Serialization(msg) => write!(f, "serialization error: {}", msg),
+            AppError::UnsupportedVersion(msg) => write!(f, "unsupported version: {}", msg),
+            AppError::NotFound(msg) => write!(f, "not found: {}", msg),
+            AppError::Validation(msg) => write!(f, "invalid value: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> Self {
+        AppError::Io(e.to_string())
+    }
+}
+
+impl From<rusqlite::Error> for AppError {
+    fn from(e: rusqlite::Error) -> Self {
+        AppError::Database(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(e: serde_json::Error) -> Self {
+        AppError::Serialization(e.to_string())
+    }
+}
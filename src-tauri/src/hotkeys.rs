@@ -0,0 +1,1361 @@
+//! Global hotkeys.
+//!
+//! The rdev listener thread in `main.rs` passes every key press to
+//! [`dispatch`], which matches it against the binding table built from
+//! `AppConfig.shortcuts` and emits only the bound [`HotkeyAction`]. Keys that
+//! match no binding never leave the backend, so typing in other applications
+//! is not observed by the webview. If the listener cannot start (e.g. no
+//! permission to observe input), the frontend forwards the keys pressed in its
+//! own window through the `dispatch_key` command instead.
+//!
+//! Pausing and splitting drive the [`RunTimer`](crate::timer::RunTimer)
+//! right here, so they keep working while the webview is suspended in the
+//! background; the frontend is told about every action to update its view.
+//!
+//! While a shortcut is being recorded (`start_record_key`), no action fires:
+//! the next key combination is captured and returned to the waiting command
+//! instead. [`CANCEL_RECORD_KEY`] or `stop_record_key` cancel the recording.
+//!
+//! Besides keys, the middle and side mouse buttons and the wheel can be bound;
+//! the listener passes them as key presses named e.g. [`MOUSE_BACK`].
+//!
+//! Besides single presses, a binding can be a two-key sequence, a double tap
+//! or a hold (see [`KeyTrigger`]). These are matched against the recent key
+//! presses and releases, so single keys can stay free for the game.
+//!
+//! [`validate_shortcuts`] reports bindings that can never fire, clash with
+//! each other or with Diablo II's own keys.
+
+use crate::models::{KeyBinding, KeyEventPayload, KeyTrigger};
+use crate::timer;
+use crate::utils::AppState;
+use rdev::Button;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Event emitted with a [`HotkeyAction`] when a bound shortcut is pressed.
+pub const HOTKEY_ACTION_EVENT: &str = "hotkey-action";
+/// Event emitted with an error message when the global listener stops.
+pub const LISTENER_ERROR_EVENT: &str = "hotkey-listener-error";
+/// Key that cancels recording a shortcut.
+pub const CANCEL_RECORD_KEY: &str = "Escape";
+/// How long `start_record_key` waits for a key by default.
+pub const DEFAULT_RECORD_TIMEOUT: Duration = Duration::from_secs(10);
+/// Longest wait a caller of `start_record_key` can ask for.
+pub const MAX_RECORD_TIMEOUT: Duration = Duration::from_secs(60);
+/// Time allowed between the keys of a recorded sequence, in milliseconds.
+pub const DEFAULT_SEQUENCE_MS: u64 = 500;
+/// Longest gap between a press and its auto-repeat, including the initial
+/// repeat delay.
+const MAX_REPEAT_GAP: Duration = Duration::from_millis(1500);
+/// Shortest timing a binding may use, in milliseconds.
+const MIN_TRIGGER_MS: u64 = 100;
+/// Longest timing a binding may use, in milliseconds.
+const MAX_TRIGGER_MS: u64 = 5000;
+
+/// An action a shortcut can be bound to, named as in `AppConfig.shortcuts`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HotkeyAction {
+    /// Save the current run and start the next one.
+    NextRun,
+    /// Pause or resume the run.
+    TogglePause,
+    /// Open or close the drop search.
+    OpenSearch,
+    /// Mark the end of the current segment.
+    Split,
+    /// Save the current run and end the session.
+    FinishSession,
+}
+
+impl HotkeyAction {
+    /// Every action, in the order the settings list them.
+    pub const ALL: [Self; 5] = [
+        Self::NextRun,
+        Self::TogglePause,
+        Self::OpenSearch,
+        Self::Split,
+        Self::FinishSession,
+    ];
+
+    /// Returns the action name used as key in `AppConfig.shortcuts`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::NextRun => "NEXT_RUN",
+            Self::TogglePause => "TOGGLE_PAUSE",
+            Self::OpenSearch => "OPEN_SEARCH",
+            Self::Split => "SPLIT",
+            Self::FinishSession => "FINISH_SESSION",
+        }
+    }
+}
+
+/// Returns the canonical form of a key name.
+///
+/// Bindings saved by older versions may use the browser's names ("P",
+/// "Enter", "Backquote") instead of rdev's ("KeyP", "Return", "BackQuote").
+pub fn normalize_key(name: &str) -> &str {
+    match name {
+        "Enter" | "NumpadEnter" | "KpReturn" | "NumEnter" => "Return",
+        "Backquote" => "BackQuote",
+        _ => name.strip_prefix("Key").unwrap_or(name),
+    }
+}
+
+/// Returns rdev's name for a browser `KeyboardEvent.code`.
+///
+/// Used for keys pressed in the app window, so that they match bindings
+/// recorded by the global listener. Codes that rdev names the same way, or
+/// has no name for, are returned unchanged.
+pub fn browser_code_to_rdev(code: &str) -> &str {
+    match code {
+        "Digit0" => "Num0",
+        "Digit1" => "Num1",
+        "Digit2" => "Num2",
+        "Digit3" => "Num3",
+        "Digit4" => "Num4",
+        "Digit5" => "Num5",
+        "Digit6" => "Num6",
+        "Digit7" => "Num7",
+        "Digit8" => "Num8",
+        "Digit9" => "Num9",
+        "ArrowUp" => "UpArrow",
+        "ArrowDown" => "DownArrow",
+        "ArrowLeft" => "LeftArrow",
+        "ArrowRight" => "RightArrow",
+        "Enter" => "Return",
+        "Backquote" => "BackQuote",
+        "BracketLeft" => "LeftBracket",
+        "BracketRight" => "RightBracket",
+        "Semicolon" => "SemiColon",
+        "Backslash" => "BackSlash",
+        "Period" => "Dot",
+        "Numpad0" => "Kp0",
+        "Numpad1" => "Kp1",
+        "Numpad2" => "Kp2",
+        "Numpad3" => "Kp3",
+        "Numpad4" => "Kp4",
+        "Numpad5" => "Kp5",
+        "Numpad6" => "Kp6",
+        "Numpad7" => "Kp7",
+        "Numpad8" => "Kp8",
+        "Numpad9" => "Kp9",
+        "NumpadEnter" => "KpReturn",
+        "NumpadSubtract" => "KpMinus",
+        "NumpadAdd" => "KpPlus",
+        "NumpadMultiply" => "KpMultiply",
+        "NumpadDivide" => "KpDivide",
+        "NumpadDecimal" => "KpDelete",
+        "AltLeft" => "Alt",
+        "AltRight" => "AltGr",
+        // Older browsers report the Windows/Command keys as "OS"
+        "OSLeft" => "MetaLeft",
+        "OSRight" => "MetaRight",
+        "Fn" => "Function",
+        _ => code,
+    }
+}
+
+/// Returns whether two bindings use the same key and modifiers, whatever
+/// their triggers.
+pub fn same_keys(a: &KeyBinding, b: &KeyBinding) -> bool {
+    normalize_key(&a.name) == normalize_key(&b.name)
+        && a.ctrl == b.ctrl
+        && a.alt == b.alt
+        && a.shift == b.shift
+        && a.meta == b.meta
+}
+
+/// Returns whether two bindings are the same key combination.
+fn same_combo(a: &KeyBinding, b: &KeyBinding) -> bool {
+    same_keys(a, b)
+        && match (&a.trigger, &b.trigger) {
+            (KeyTrigger::Sequence { first: x, .. }, KeyTrigger::Sequence { first: y, .. }) => {
+                normalize_key(x) == normalize_key(y)
+            }
+            (x, y) => std::mem::discriminant(x) == std::mem::discriminant(y),
+        }
+}
+
+/// Returns whether two bindings fire on the same key press with different
+/// triggers: a press binding and a hold, double tap or the last key of a
+/// sequence.
+fn overlaps(a: &KeyBinding, b: &KeyBinding) -> bool {
+    same_keys(a, b)
+        && matches!(
+            (&a.trigger, &b.trigger),
+            (KeyTrigger::Press, t) | (t, KeyTrigger::Press) if *t != KeyTrigger::Press
+        )
+}
+
+/// Returns whether a key press matches a binding, modifiers included.
+pub fn matches(binding: &KeyBinding, key: &KeyEventPayload) -> bool {
+    normalize_key(&binding.name) == normalize_key(&key.name)
+        && binding.ctrl == key.ctrl
+        && binding.alt == key.alt
+        && binding.shift == key.shift
+        && binding.meta == key.meta
+}
+
+/// A shortcut recording in progress.
+struct Capture {
+    /// Identifies the recording, so a timed-out caller cannot end a newer one.
+    id: u64,
+    /// Whether a two-key sequence is recorded.
+    sequence: bool,
+    /// The first key of a sequence, once pressed.
+    first: Option<String>,
+    /// Receives the captured binding, or `None` when cancelled.
+    sender: Sender<Option<KeyBinding>>,
+}
+
+/// A key that is held down.
+struct HeldKey {
+    /// The press that started holding it.
+    key: KeyEventPayload,
+    /// When it was pressed.
+    since: Instant,
+    /// When it was last pressed or auto-repeated.
+    last_seen: Instant,
+    /// Whether a hold binding already fired for this press.
+    fired: bool,
+}
+
+/// Recent key presses, for bindings that depend on timing.
+#[derive(Default)]
+struct KeyHistory {
+    /// The last press other than an auto-repeat, and when it happened.
+    last_press: Option<(KeyEventPayload, Instant)>,
+    /// Keys held down, by canonical name.
+    held: HashMap<String, HeldKey>,
+}
+
+/// The shortcut binding table and recording state, held in [`AppState`].
+#[derive(Default)]
+pub struct Hotkeys {
+    bindings: RwLock<Vec<(HotkeyAction, KeyBinding)>>,
+    history: Mutex<KeyHistory>,
+    capture: Mutex<Option<Capture>>,
+    next_capture_id: Mutex<u64>,
+    /// Why the global listener stopped, if it did.
+    listener_error: Mutex<Option<String>>,
+}
+
+impl Hotkeys {
+    fn bindings(&self) -> RwLockReadGuard<'_, Vec<(HotkeyAction, KeyBinding)>> {
+        self.bindings.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn history(&self) -> MutexGuard<'_, KeyHistory> {
+        self.history.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn capture(&self) -> MutexGuard<'_, Option<Capture>> {
+        self.capture.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the first action whose binding matches the key and trigger.
+    fn find(
+        &self,
+        key: &KeyEventPayload,
+        trigger: impl Fn(&KeyTrigger) -> bool,
+    ) -> Option<HotkeyAction> {
+        self.bindings()
+            .iter()
+            .find(|(_, binding)| matches(binding, key) && trigger(&binding.trigger))
+            .map(|&(action, _)| action)
+    }
+
+    /// Replaces the binding table. Entries for unknown actions are ignored.
+    ///
+    /// # Arguments
+    /// * `shortcuts` - The shortcuts of the app config.
+    pub fn set_bindings(&self, shortcuts: &HashMap<String, KeyBinding>) {
+        let bindings = HotkeyAction::ALL
+            .iter()
+            .filter_map(|&action| Some((action, shortcuts.get(action.name())?.clone())))
+            .collect();
+        *self.bindings.write().unwrap_or_else(|e| e.into_inner()) = bindings;
+    }
+
+    /// Starts recording a shortcut, cancelling any recording in progress.
+    ///
+    /// # Arguments
+    /// * `sequence` - Whether to record two keys as a [`KeyTrigger::Sequence`].
+    ///
+    /// # Returns
+    /// The recording ID for [`Hotkeys::finish_capture`], and the receiver of
+    /// the captured binding (`None` if the recording is cancelled).
+    pub fn start_capture(&self, sequence: bool) -> (u64, Receiver<Option<KeyBinding>>) {
+        let id = {
+            let mut next = self
+                .next_capture_id
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            *next += 1;
+            *next
+        };
+        let (sender, receiver) = mpsc::channel();
+        let capture = Capture {
+            id,
+            sequence,
+            first: None,
+            sender,
+        };
+        if let Some(previous) = self.capture().replace(capture) {
+            let _ = previous.sender.send(None);
+        }
+        (id, receiver)
+    }
+
+    /// Ends a recording, e.g. after its timeout. Does nothing if the
+    /// recording already ended or a newer one started.
+    pub fn finish_capture(&self, id: u64) {
+        let mut capture = self.capture();
+        if capture.as_ref().is_some_and(|c| c.id == id) {
+            *capture = None;
+        }
+    }
+
+    /// Records that the global listener stopped.
+    pub fn set_listener_error(&self, message: String) {
+        *self
+            .listener_error
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(message);
+    }
+
+    /// Returns why the global listener stopped, or `None` while it runs.
+    pub fn listener_error(&self) -> Option<String> {
+        self.listener_error
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Cancels the recording in progress, if any.
+    pub fn cancel_capture(&self) {
+        if let Some(capture) = self.capture().take() {
+            let _ = capture.sender.send(None);
+        }
+    }
+
+    /// Records a key press while a recording is in progress.
+    ///
+    /// # Returns
+    /// Whether the key was consumed by a recording.
+    fn record(&self, key: &KeyEventPayload) -> bool {
+        let mut slot = self.capture();
+        let Some(capture) = slot.as_mut() else {
+            return false;
+        };
+        let cancel =
+            key.name == CANCEL_RECORD_KEY && !(key.ctrl || key.alt || key.shift || key.meta);
+        if capture.sequence && capture.first.is_none() && !cancel {
+            capture.first = Some(key.name.clone());
+            return true;
+        }
+        let trigger = match capture.first.take() {
+            Some(first) => KeyTrigger::Sequence {
+                first,
+                within_ms: DEFAULT_SEQUENCE_MS,
+            },
+            None => KeyTrigger::Press,
+        };
+        let binding = (!cancel).then(|| KeyBinding {
+            keycode: None,
+            alt: key.alt,
+            ctrl: key.ctrl,
+            shift: key.shift,
+            meta: key.meta,
+            name: key.name.clone(),
+            trigger,
+        });
+        if let Some(capture) = slot.take() {
+            let _ = capture.sender.send(binding);
+        }
+        true
+    }
+
+    /// Resolves a key press.
+    ///
+    /// While recording, the key is captured instead (see `start_record_key`)
+    /// and no action fires. Otherwise sequence and double-tap bindings take
+    /// precedence over single presses; hold bindings fire on auto-repeat once
+    /// the key has been held long enough, or on release. Other bindings
+    /// ignore auto-repeat.
+    ///
+    /// # Arguments
+    /// * `key` - The pressed key and modifiers.
+    /// * `at` - When the key was pressed.
+    ///
+    /// # Returns
+    /// The action to fire, or `None` if the key is not bound or was recorded.
+    pub fn press(&self, key: KeyEventPayload, at: Instant) -> Option<HotkeyAction> {
+        if self.record(&key) {
+            return None;
+        }
+        let mut history = self.history();
+        let name = normalize_key(&key.name).to_string();
+
+        // Auto-repeat while the key is held down. A press long after the last
+        // one means its release was missed (e.g. behind an elevated window).
+        let repeat = history
+            .held
+            .get(&name)
+            .is_some_and(|held| at.saturating_duration_since(held.last_seen) < MAX_REPEAT_GAP);
+        if let Some(held) = history.held.get_mut(&name).filter(|_| repeat) {
+            held.last_seen = at;
+            let held_ms = at.saturating_duration_since(held.since).as_millis() as u64;
+            if !held.fired {
+                let hold = self.find(
+                    &held.key,
+                    |t| matches!(t, KeyTrigger::Hold { hold_ms } if held_ms >= *hold_ms),
+                );
+                if hold.is_some() {
+                    held.fired = true;
+                    return hold;
+                }
+            }
+            // Only holds look at repeats; a press binding fires once per press
+            return None;
+        }
+
+        history.held.insert(
+            name.clone(),
+            HeldKey {
+                key: key.clone(),
+                since: at,
+                last_seen: at,
+                fired: false,
+            },
+        );
+        let previous = history.last_press.replace((key.clone(), at));
+        if let Some((previous, previous_at)) = previous {
+            let elapsed_ms = at.saturating_duration_since(previous_at).as_millis() as u64;
+            let same_modifiers = previous.ctrl == key.ctrl
+                && previous.alt == key.alt
+                && previous.shift == key.shift
+                && previous.meta == key.meta;
+            let previous_name = normalize_key(&previous.name);
+            let action = self.find(&key, |t| match t {
+                KeyTrigger::Sequence { first, within_ms } => {
+                    same_modifiers
+                        && normalize_key(first) == previous_name
+                        && elapsed_ms <= *within_ms
+                }
+                KeyTrigger::DoubleTap { within_ms } => {
+                    same_modifiers && previous_name == name && elapsed_ms <= *within_ms
+                }
+                _ => false,
+            });
+            if action.is_some() {
+                // A third press starts over instead of firing again
+                history.last_press = None;
+                return action;
+            }
+        }
+        self.find(&key, |t| *t == KeyTrigger::Press)
+    }
+
+    /// Resolves a key release.
+    ///
+    /// Like [`Hotkeys::press`], no action fires while recording.
+    ///
+    /// # Arguments
+    /// * `name` - The released key.
+    /// * `at` - When the key was released.
+    ///
+    /// # Returns
+    /// The hold action to fire, if the key was held long enough and its hold
+    /// binding has not fired yet.
+    pub fn release(&self, name: &str, at: Instant) -> Option<HotkeyAction> {
+        let held = self.history().held.remove(normalize_key(name))?;
+        if held.fired || self.capture().is_some() {
+            return None;
+        }
+        let held_ms = at.saturating_duration_since(held.since).as_millis() as u64;
+        self.find(
+            &held.key,
+            |t| matches!(t, KeyTrigger::Hold { hold_ms } if held_ms >= *hold_ms),
+        )
+    }
+}
+
+/// Resolves a key press from the global listener and fires the bound action.
+pub fn dispatch(app: &AppHandle, key: KeyEventPayload) {
+    let state = app.state::<AppState>();
+    if let Some(action) = state.hotkeys.press(key, Instant::now()) {
+        fire(app, &state, action);
+    }
+}
+
+/// Resolves a key release from the global listener and fires the bound action.
+pub fn dispatch_release(app: &AppHandle, name: &str) {
+    let state = app.state::<AppState>();
+    if let Some(action) = state.hotkeys.release(name, Instant::now()) {
+        fire(app, &state, action);
+    }
+}
+
+/// Applies the timer part of an action and emits it.
+///
+/// A paused run is not split, matching the frontend's split button.
+fn fire(app: &AppHandle, state: &AppState, action: HotkeyAction) {
+    let snapshot = match action {
+        HotkeyAction::TogglePause => state.timer.toggle_pause().ok(),
+        HotkeyAction::Split => state.timer.split_running(),
+        _ => None,
+    };
+    if let Some(snapshot) = snapshot {
+        timer::publish(app, state, snapshot);
+    }
+    let _ = app.emit(HOTKEY_ACTION_EVENT, action);
+}
+
+// ============================================================================
+// Mouse
+// ============================================================================
+
+/// Binding name of the middle mouse button.
+pub const MOUSE_MIDDLE: &str = "MouseMiddle";
+/// Binding name of the back side button.
+pub const MOUSE_BACK: &str = "MouseBack";
+/// Binding name of the forward side button.
+pub const MOUSE_FORWARD: &str = "MouseForward";
+/// Binding name of a wheel step up.
+pub const WHEEL_UP: &str = "WheelUp";
+/// Binding name of a wheel step down.
+pub const WHEEL_DOWN: &str = "WheelDown";
+
+/// Returns the binding name of a mouse button, if it can be bound.
+///
+/// Left and right clicks stay with the game. rdev reports side buttons by
+/// number: 1 and 2 on Windows, 8 and 9 on Linux; macOS does not report them.
+pub fn mouse_button_name(button: Button) -> Option<&'static str> {
+    match button {
+        Button::Middle => Some(MOUSE_MIDDLE),
+        Button::Unknown(1 | 8) => Some(MOUSE_BACK),
+        Button::Unknown(2 | 9) => Some(MOUSE_FORWARD),
+        _ => None,
+    }
+}
+
+/// Returns the binding name of a vertical wheel movement, if any.
+pub fn wheel_name(delta_y: i64) -> Option<&'static str> {
+    match delta_y.signum() {
+        1 => Some(WHEEL_UP),
+        -1 => Some(WHEEL_DOWN),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+/// Modifier keys. The listener tracks them instead of dispatching them, so a
+/// binding on one of them never fires.
+const MODIFIER_KEYS: [&str; 8] = [
+    "Alt",
+    "AltGr",
+    "ControlLeft",
+    "ControlRight",
+    "ShiftLeft",
+    "ShiftRight",
+    "MetaLeft",
+    "MetaRight",
+];
+
+/// Names of the other keys rdev reports.
+const RDEV_KEYS: &[&str] = &[
+    "Backspace",
+    "CapsLock",
+    "Delete",
+    "DownArrow",
+    "End",
+    "Escape",
+    "F1",
+    "F2",
+    "F3",
+    "F4",
+    "F5",
+    "F6",
+    "F7",
+    "F8",
+    "F9",
+    "F10",
+    "F11",
+    "F12",
+    "Home",
+    "LeftArrow",
+    "PageDown",
+    "PageUp",
+    "Return",
+    "RightArrow",
+    "Space",
+    "Tab",
+    "UpArrow",
+    "PrintScreen",
+    "ScrollLock",
+    "Pause",
+    "NumLock",
+    "BackQuote",
+    "Num1",
+    "Num2",
+    "Num3",
+    "Num4",
+    "Num5",
+    "Num6",
+    "Num7",
+    "Num8",
+    "Num9",
+    "Num0",
+    "Minus",
+    "Equal",
+    "KeyQ",
+    "KeyW",
+    "KeyE",
+    "KeyR",
+    "KeyT",
+    "KeyY",
+    "KeyU",
+    "KeyI",
+    "KeyO",
+    "KeyP",
+    "LeftBracket",
+    "RightBracket",
+    "KeyA",
+    "KeyS",
+    "KeyD",
+    "KeyF",
+    "KeyG",
+    "KeyH",
+    "KeyJ",
+    "KeyK",
+    "KeyL",
+    "SemiColon",
+    "Quote",
+    "BackSlash",
+    "IntlBackslash",
+    "KeyZ",
+    "KeyX",
+    "KeyC",
+    "KeyV",
+    "KeyB",
+    "KeyN",
+    "KeyM",
+    "Comma",
+    "Dot",
+    "Slash",
+    "Insert",
+    "KpReturn",
+    "KpMinus",
+    "KpPlus",
+    "KpMultiply",
+    "KpDivide",
+    "Kp0",
+    "Kp1",
+    "Kp2",
+    "Kp3",
+    "Kp4",
+    "Kp5",
+    "Kp6",
+    "Kp7",
+    "Kp8",
+    "Kp9",
+    "KpDelete",
+    "Function",
+    "F13",
+    "F14",
+    "F15",
+    "F16",
+    "F17",
+    "F18",
+];
+
+/// Diablo II's default keys that work without a modifier, with what they do.
+const GAME_KEYS: &[(&str, &str)] = &[
+    ("Tab", "Automap"),
+    ("I", "Inventory"),
+    ("C", "Character Screen"),
+    ("T", "Skill Tree"),
+    ("S", "Skill Speed Bar"),
+    ("Q", "Quest Log"),
+    ("P", "Party Screen"),
+    ("M", "Message Log"),
+    ("H", "Help Screen"),
+    ("W", "Swap Weapons"),
+    ("R", "Toggle Run/Walk"),
+    ("Z", "Show Portraits"),
+    ("Space", "Clear Screen"),
+    ("Return", "Chat"),
+    ("Escape", "Game Menu"),
+    ("F1", "Skill 1"),
+    ("F2", "Skill 2"),
+    ("F3", "Skill 3"),
+    ("F4", "Skill 4"),
+    ("F5", "Skill 5"),
+    ("F6", "Skill 6"),
+    ("F7", "Skill 7"),
+    ("F8", "Skill 8"),
+    ("Num1", "Belt Slot 1"),
+    ("Num2", "Belt Slot 2"),
+    ("Num3", "Belt Slot 3"),
+    ("Num4", "Belt Slot 4"),
+];
+
+/// How serious a shortcut diagnostic is.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The binding is rejected by `save_config`.
+    Error,
+    /// The binding works but probably not as intended.
+    Warning,
+}
+
+/// A problem with a shortcut binding.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ShortcutIssue {
+    /// The action does not exist, so the binding is ignored.
+    UnknownAction,
+    /// The key name is not one rdev reports, so the binding never fires.
+    UnknownKey,
+    /// The binding is a bare modifier key, so it never fires.
+    ModifierOnly,
+    /// Another action has the same key combination.
+    Duplicate { with: String },
+    /// Another action fires on the same key with a different trigger, e.g. a
+    /// press binding on the key of a hold, double tap or sequence, so both
+    /// actions fire.
+    Overlap { with: String },
+    /// The key also does something in Diablo II, e.g. "Inventory".
+    GameKey { usage: String },
+    /// The sequence, double-tap or hold time is out of range.
+    InvalidTiming { min_ms: u64, max_ms: u64 },
+    /// The wheel cannot be held, so a hold binding on it never fires.
+    WheelHold,
+}
+
+impl ShortcutIssue {
+    /// Returns how serious the issue is.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::UnknownAction | Self::GameKey { .. } | Self::Overlap { .. } => Severity::Warning,
+            Self::UnknownKey
+            | Self::ModifierOnly
+            | Self::Duplicate { .. }
+            | Self::InvalidTiming { .. }
+            | Self::WheelHold => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for ShortcutIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownAction => write!(f, "unknown action"),
+            Self::UnknownKey => write!(f, "unknown key"),
+            Self::ModifierOnly => write!(f, "a modifier key cannot be bound on its own"),
+            Self::Duplicate { with } => write!(f, "same key as {}", with),
+            Self::Overlap { with } => write!(f, "pressing the key also fires {}", with),
+            Self::GameKey { usage } => write!(f, "collides with Diablo II's {} key", usage),
+            Self::InvalidTiming { min_ms, max_ms } => {
+                write!(f, "time must be between {} and {} ms", min_ms, max_ms)
+            }
+            Self::WheelHold => write!(f, "the mouse wheel cannot be held"),
+        }
+    }
+}
+
+/// A problem found by [`validate_shortcuts`].
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ShortcutDiagnostic {
+    /// The action name, as in `AppConfig.shortcuts`.
+    pub action: String,
+    /// How serious the issue is.
+    pub severity: Severity,
+    /// What is wrong.
+    #[serde(flatten)]
+    pub issue: ShortcutIssue,
+}
+
+/// Returns whether rdev reports a key or mouse button of this name.
+fn is_known_key(name: &str) -> bool {
+    // Keys rdev has no variant for are reported by scan code
+    name.starts_with("Unknown(")
+        || [
+            MOUSE_MIDDLE,
+            MOUSE_BACK,
+            MOUSE_FORWARD,
+            WHEEL_UP,
+            WHEEL_DOWN,
+        ]
+        .contains(&name)
+        || RDEV_KEYS
+            .iter()
+            .any(|known| normalize_key(known) == normalize_key(name))
+}
+
+/// Returns what a binding does in Diablo II, if it collides with a game key.
+fn game_key_usage(binding: &KeyBinding) -> Option<&'static str> {
+    // Ctrl, Alt and Win combinations are not game keys; Shift is "stand still".
+    // Sequences, double taps and holds are not triggered by normal play.
+    if binding.ctrl || binding.alt || binding.meta || binding.trigger != KeyTrigger::Press {
+        return None;
+    }
+    GAME_KEYS
+        .iter()
+        .find(|(key, _)| *key == normalize_key(&binding.name))
+        .map(|&(_, usage)| usage)
+}
+
+/// Checks a shortcut table.
+///
+/// # Arguments
+/// * `shortcuts` - The shortcuts of the app config.
+///
+/// # Returns
+/// Every issue found, grouped by action in settings order.
+pub fn validate_shortcuts(shortcuts: &HashMap<String, KeyBinding>) -> Vec<ShortcutDiagnostic> {
+    let mut actions: Vec<&String> = shortcuts.keys().collect();
+    let order = |action: &str| {
+        HotkeyAction::ALL
+            .iter()
+            .position(|a| a.name() == action)
+            .unwrap_or(HotkeyAction::ALL.len())
+    };
+    actions.sort_by(|a, b| order(a).cmp(&order(b)).then(a.cmp(b)));
+
+    let mut diagnostics = Vec::new();
+    for &action in &actions {
+        let binding = &shortcuts[action];
+        let mut issues = Vec::new();
+        if order(action) == HotkeyAction::ALL.len() {
+            issues.push(ShortcutIssue::UnknownAction);
+        }
+        let first = match &binding.trigger {
+            KeyTrigger::Sequence { first, .. } => Some(first.as_str()),
+            _ => None,
+        };
+        for name in first.into_iter().chain([binding.name.as_str()]) {
+            if MODIFIER_KEYS.contains(&name) {
+                issues.push(ShortcutIssue::ModifierOnly);
+            } else if !is_known_key(name) {
+                issues.push(ShortcutIssue::UnknownKey);
+            }
+        }
+        if issues.is_empty() {
+            if let Some(usage) = game_key_usage(binding) {
+                issues.push(ShortcutIssue::GameKey {
+                    usage: usage.to_string(),
+                });
+            }
+        }
+        let timing = match binding.trigger {
+            KeyTrigger::Press => None,
+            KeyTrigger::Sequence { within_ms, .. } | KeyTrigger::DoubleTap { within_ms } => {
+                Some(within_ms)
+            }
+            KeyTrigger::Hold { hold_ms } => Some(hold_ms),
+        };
+        if matches!(binding.trigger, KeyTrigger::Hold { .. })
+            && [WHEEL_UP, WHEEL_DOWN].contains(&binding.name.as_str())
+        {
+            issues.push(ShortcutIssue::WheelHold);
+        }
+        if timing.is_some_and(|ms| !(MIN_TRIGGER_MS..=MAX_TRIGGER_MS).contains(&ms)) {
+            issues.push(ShortcutIssue::InvalidTiming {
+                min_ms: MIN_TRIGGER_MS,
+                max_ms: MAX_TRIGGER_MS,
+            });
+        }
+        issues.extend(
+            actions
+                .iter()
+                .filter(|&&other| other != action && same_combo(binding, &shortcuts[other]))
+                .map(|&other| ShortcutIssue::Duplicate {
+                    with: other.clone(),
+                }),
+        );
+        issues.extend(
+            actions
+                .iter()
+                .filter(|&&other| other != action && overlaps(binding, &shortcuts[other]))
+                .map(|&other| ShortcutIssue::Overlap {
+                    with: other.clone(),
+                }),
+        );
+        diagnostics.extend(issues.into_iter().map(|issue| ShortcutDiagnostic {
+            action: action.clone(),
+            severity: issue.severity(),
+            issue,
+        }));
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AppConfig;
+
+    fn key(name: &str, alt: bool) -> KeyEventPayload {
+        KeyEventPayload {
+            name: name.to_string(),
+            alt,
+            ctrl: false,
+            shift: false,
+            meta: false,
+        }
+    }
+
+    /// Presses and releases a key.
+    fn tap(hotkeys: &Hotkeys, key: KeyEventPayload) -> Option<HotkeyAction> {
+        let name = key.name.clone();
+        let action = hotkeys.press(key, Instant::now());
+        hotkeys.release(&name, Instant::now());
+        action
+    }
+
+    /// Binds one action to `name` with the given trigger.
+    fn bind(trigger: KeyTrigger, name: &str) -> Hotkeys {
+        let mut shortcuts = HashMap::new();
+        shortcuts.insert(
+            "SPLIT".to_string(),
+            KeyBinding {
+                keycode: None,
+                alt: false,
+                ctrl: false,
+                shift: false,
+                meta: false,
+                name: name.to_string(),
+                trigger,
+            },
+        );
+        let hotkeys = Hotkeys::default();
+        hotkeys.set_bindings(&shortcuts);
+        hotkeys
+    }
+
+    #[test]
+    fn bound_keys_resolve_to_actions() {
+        let hotkeys = Hotkeys::default();
+        hotkeys.set_bindings(&AppConfig::default().shortcuts);
+
+        assert_eq!(
+            tap(&hotkeys, key("BackQuote", false)),
+            Some(HotkeyAction::NextRun)
+        );
+        assert_eq!(
+            tap(&hotkeys, key("KeyP", true)),
+            Some(HotkeyAction::TogglePause)
+        );
+        // Modifiers must match exactly
+        assert_eq!(tap(&hotkeys, key("KeyP", false)), None);
+        let mut meta = key("KeyP", true);
+        meta.meta = true;
+        assert_eq!(tap(&hotkeys, meta), None);
+        assert_eq!(tap(&hotkeys, key("KeyA", false)), None);
+    }
+
+    #[test]
+    fn legacy_key_names_still_match() {
+        let mut shortcuts = HashMap::new();
+        shortcuts.insert(
+            "SPLIT".to_string(),
+            KeyBinding {
+                keycode: None,
+                alt: false,
+                ctrl: false,
+                shift: false,
+                meta: false,
+                name: "Enter".to_string(),
+                trigger: KeyTrigger::Press,
+            },
+        );
+        shortcuts.insert("UNKNOWN".to_string(), shortcuts["SPLIT"].clone());
+        let hotkeys = Hotkeys::default();
+        hotkeys.set_bindings(&shortcuts);
+
+        assert_eq!(
+            tap(&hotkeys, key("Return", false)),
+            Some(HotkeyAction::Split)
+        );
+    }
+
+    #[test]
+    fn browser_codes_map_to_rdev_names() {
+        let cases = [
+            ("Digit1", "Num1"),
+            ("Digit0", "Num0"),
+            ("ArrowUp", "UpArrow"),
+            ("ArrowLeft", "LeftArrow"),
+            ("BracketLeft", "LeftBracket"),
+            ("Semicolon", "SemiColon"),
+            ("Period", "Dot"),
+            ("Backslash", "BackSlash"),
+            ("Numpad1", "Kp1"),
+            ("NumpadEnter", "KpReturn"),
+            ("NumpadDecimal", "KpDelete"),
+        ];
+        for (code, name) in cases {
+            assert_eq!(browser_code_to_rdev(code), name);
+            assert!(is_known_key(name), "{}", code);
+        }
+        // Codes rdev names the same way pass through
+        for code in ["KeyP", "Slash", "Comma", "Minus", "F5"] {
+            assert_eq!(browser_code_to_rdev(code), code);
+            assert!(is_known_key(code), "{}", code);
+        }
+        for code in ["AltLeft", "OSLeft", "ControlLeft", "MetaLeft"] {
+            assert!(
+                MODIFIER_KEYS.contains(&browser_code_to_rdev(code)),
+                "{}",
+                code
+            );
+        }
+
+        let hotkeys = bind(KeyTrigger::Press, "Kp1");
+        assert_eq!(
+            tap(&hotkeys, key(browser_code_to_rdev("Numpad1"), false)),
+            Some(HotkeyAction::Split)
+        );
+    }
+
+    #[test]
+    fn recording_captures_the_next_key_and_suppresses_actions() {
+        let hotkeys = Hotkeys::default();
+        hotkeys.set_bindings(&AppConfig::default().shortcuts);
+
+        let (_, receiver) = hotkeys.start_capture(false);
+        let mut combo = key("BackQuote", false);
+        combo.meta = true;
+        assert_eq!(tap(&hotkeys, combo), None);
+        let binding = receiver.try_recv().unwrap().unwrap();
+        assert_eq!((binding.name.as_str(), binding.meta), ("BackQuote", true));
+
+        // Recording ended, so the next key fires its action again
+        assert_eq!(
+            tap(&hotkeys, key("BackQuote", false)),
+            Some(HotkeyAction::NextRun)
+        );
+    }
+
+    #[test]
+    fn recording_can_be_cancelled() {
+        let hotkeys = Hotkeys::default();
+
+        let (_, receiver) = hotkeys.start_capture(false);
+        assert_eq!(tap(&hotkeys, key(CANCEL_RECORD_KEY, false)), None);
+        assert_eq!(receiver.try_recv().unwrap(), None);
+
+        // Starting a new recording cancels the previous one
+        let (first, receiver) = hotkeys.start_capture(false);
+        let (_, newer) = hotkeys.start_capture(false);
+        assert_eq!(receiver.try_recv().unwrap(), None);
+        // A stale timeout does not end the newer recording
+        hotkeys.finish_capture(first);
+        tap(&hotkeys, key("F5", false));
+        assert_eq!(newer.try_recv().unwrap().unwrap().name, "F5");
+
+        hotkeys.start_capture(false);
+        hotkeys.cancel_capture();
+        assert_eq!(tap(&hotkeys, key("F5", false)), None);
+    }
+
+    #[test]
+    fn default_shortcuts_are_valid() {
+        assert!(validate_shortcuts(&AppConfig::default().shortcuts).is_empty());
+    }
+
+    #[test]
+    fn invalid_shortcuts_are_diagnosed_per_action() {
+        let mut shortcuts = AppConfig::default().shortcuts;
+        let mut binding = |action: &str, name: &str| {
+            let binding = shortcuts.get_mut(action).unwrap();
+            binding.name = name.to_string();
+            binding.alt = false;
+        };
+        binding("TOGGLE_PAUSE", "KeyI");
+        binding("OPEN_SEARCH", "ShiftLeft");
+        binding("SPLIT", "Foo");
+        binding("FINISH_SESSION", "Backquote");
+
+        let diagnostics = validate_shortcuts(&shortcuts);
+        let found: Vec<(&str, Severity, ShortcutIssue)> = diagnostics
+            .iter()
+            .map(|d| (d.action.as_str(), d.severity, d.issue.clone()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "NEXT_RUN",
+                    Severity::Error,
+                    ShortcutIssue::Duplicate {
+                        with: "FINISH_SESSION".to_string()
+                    }
+                ),
+                (
+                    "TOGGLE_PAUSE",
+                    Severity::Warning,
+                    ShortcutIssue::GameKey {
+                        usage: "Inventory".to_string()
+                    }
+                ),
+                ("OPEN_SEARCH", Severity::Error, ShortcutIssue::ModifierOnly),
+                ("SPLIT", Severity::Error, ShortcutIssue::UnknownKey),
+                (
+                    "FINISH_SESSION",
+                    Severity::Error,
+                    ShortcutIssue::Duplicate {
+                        with: "NEXT_RUN".to_string()
+                    }
+                ),
+            ]
+        );
+
+        // A modifier makes a game key safe; unknown actions are only ignored
+        shortcuts.get_mut("TOGGLE_PAUSE").unwrap().ctrl = true;
+        shortcuts.insert("LEGACY".to_string(), shortcuts["SPLIT"].clone());
+        let diagnostics = validate_shortcuts(&shortcuts);
+        assert!(!diagnostics.iter().any(|d| d.action == "TOGGLE_PAUSE"));
+        assert_eq!(
+            diagnostics.last().map(|d| (d.action.as_str(), d.severity)),
+            Some(("LEGACY", Severity::Error))
+        );
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.action == "LEGACY" && d.issue == ShortcutIssue::UnknownAction));
+    }
+
+    #[test]
+    fn sequences_fire_only_within_their_window() {
+        let hotkeys = bind(
+            KeyTrigger::Sequence {
+                first: "KeyG".to_string(),
+                within_ms: 500,
+            },
+            "KeyN",
+        );
+        let start = Instant::now();
+        let ms = |ms| start + Duration::from_millis(ms);
+
+        assert_eq!(hotkeys.press(key("KeyN", false), ms(0)), None);
+        hotkeys.release("KeyN", ms(50));
+        assert_eq!(hotkeys.press(key("KeyG", false), ms(100)), None);
+        hotkeys.release("KeyG", ms(150));
+        assert_eq!(
+            hotkeys.press(key("KeyN", false), ms(400)),
+            Some(HotkeyAction::Split)
+        );
+        hotkeys.release("KeyN", ms(450));
+
+        // Too slow
+        hotkeys.press(key("KeyG", false), ms(1000));
+        hotkeys.release("KeyG", ms(1050));
+        assert_eq!(hotkeys.press(key("KeyN", false), ms(1600)), None);
+        hotkeys.release("KeyN", ms(1650));
+
+        // Another key in between breaks the sequence
+        hotkeys.press(key("KeyG", false), ms(2000));
+        hotkeys.release("KeyG", ms(2010));
+        hotkeys.press(key("KeyA", false), ms(2020));
+        hotkeys.release("KeyA", ms(2030));
+        assert_eq!(hotkeys.press(key("KeyN", false), ms(2040)), None);
+    }
+
+    #[test]
+    fn double_taps_ignore_auto_repeat_and_a_third_tap() {
+        let hotkeys = bind(KeyTrigger::DoubleTap { within_ms: 300 }, "F9");
+        let start = Instant::now();
+        let ms = |ms| start + Duration::from_millis(ms);
+
+        assert_eq!(hotkeys.press(key("F9", false), ms(0)), None);
+        // Auto-repeat is not a second tap
+        assert_eq!(hotkeys.press(key("F9", false), ms(30)), None);
+        hotkeys.release("F9", ms(60));
+        assert_eq!(
+            hotkeys.press(key("F9", false), ms(200)),
+            Some(HotkeyAction::Split)
+        );
+        hotkeys.release("F9", ms(250));
+        assert_eq!(hotkeys.press(key("F9", false), ms(300)), None);
+    }
+
+    #[test]
+    fn presses_ignore_auto_repeat() {
+        let hotkeys = bind(KeyTrigger::Press, "F9");
+        let start = Instant::now();
+        let ms = |ms| start + Duration::from_millis(ms);
+
+        assert_eq!(
+            hotkeys.press(key("F9", false), ms(0)),
+            Some(HotkeyAction::Split)
+        );
+        assert_eq!(hotkeys.press(key("F9", false), ms(500)), None);
+        assert_eq!(hotkeys.press(key("F9", false), ms(530)), None);
+        assert_eq!(hotkeys.release("F9", ms(560)), None);
+        assert_eq!(
+            hotkeys.press(key("F9", false), ms(700)),
+            Some(HotkeyAction::Split)
+        );
+    }
+
+    #[test]
+    fn holds_fire_once_after_their_time() {
+        let hotkeys = bind(KeyTrigger::Hold { hold_ms: 600 }, "KeyX");
+        let start = Instant::now();
+        let ms = |ms| start + Duration::from_millis(ms);
+
+        // Released too early
+        assert_eq!(hotkeys.press(key("KeyX", false), ms(0)), None);
+        assert_eq!(hotkeys.release("KeyX", ms(200)), None);
+
+        // Fires on auto-repeat, then not again on release
+        hotkeys.press(key("KeyX", false), ms(1000));
+        assert_eq!(hotkeys.press(key("KeyX", false), ms(1500)), None);
+        assert_eq!(
+            hotkeys.press(key("KeyX", false), ms(1700)),
+            Some(HotkeyAction::Split)
+        );
+        assert_eq!(hotkeys.press(key("KeyX", false), ms(1730)), None);
+        assert_eq!(hotkeys.release("KeyX", ms(1800)), None);
+
+        // Without auto-repeat, fires on release
+        hotkeys.press(key("KeyX", false), ms(3000));
+        assert_eq!(hotkeys.release("KeyX", ms(3700)), Some(HotkeyAction::Split));
+
+        // But not if a recording started while the key was down
+        hotkeys.press(key("KeyX", false), ms(5000));
+        let (_, receiver) = hotkeys.start_capture(false);
+        assert_eq!(hotkeys.release("KeyX", ms(5700)), None);
+        hotkeys.cancel_capture();
+        assert_eq!(receiver.try_recv().unwrap(), None);
+    }
+
+    #[test]
+    fn sequences_can_be_recorded() {
+        let hotkeys = Hotkeys::default();
+
+        let (_, receiver) = hotkeys.start_capture(true);
+        tap(&hotkeys, key("KeyG", false));
+        assert!(receiver.try_recv().is_err());
+        tap(&hotkeys, key("KeyN", false));
+        let binding = receiver.try_recv().unwrap().unwrap();
+        assert_eq!(binding.name, "KeyN");
+        assert_eq!(
+            binding.trigger,
+            KeyTrigger::Sequence {
+                first: "KeyG".to_string(),
+                within_ms: DEFAULT_SEQUENCE_MS,
+            }
+        );
+    }
+
+    #[test]
+    fn trigger_timings_and_sequence_keys_are_validated() {
+        let mut shortcuts = AppConfig::default().shortcuts;
+        let split = shortcuts.get_mut("SPLIT").unwrap();
+        split.alt = false;
+        split.trigger = KeyTrigger::Sequence {
+            first: "ShiftLeft".to_string(),
+            within_ms: 10_000,
+        };
+        let issues: Vec<ShortcutIssue> = validate_shortcuts(&shortcuts)
+            .into_iter()
+            .map(|d| d.issue)
+            .collect();
+        // No game key warning: "S" only fires after the first key
+        assert_eq!(
+            issues,
+            vec![
+                ShortcutIssue::ModifierOnly,
+                ShortcutIssue::InvalidTiming {
+                    min_ms: MIN_TRIGGER_MS,
+                    max_ms: MAX_TRIGGER_MS,
+                },
+            ]
+        );
+
+        // A hold on the key of a press binding is not a duplicate, but
+        // both actions fire
+        let mut shortcuts = AppConfig::default().shortcuts;
+        shortcuts.get_mut("SPLIT").unwrap().trigger = KeyTrigger::Hold { hold_ms: 600 };
+        shortcuts.get_mut("SPLIT").unwrap().name = "KeyP".to_string();
+        let diagnostics = validate_shortcuts(&shortcuts);
+        let found: Vec<(&str, &ShortcutIssue)> = diagnostics
+            .iter()
+            .map(|d| (d.action.as_str(), &d.issue))
+            .collect();
+        let overlap = |with: &str| ShortcutIssue::Overlap {
+            with: with.to_string(),
+        };
+        assert_eq!(
+            found,
+            vec![
+                ("TOGGLE_PAUSE", &overlap("SPLIT")),
+                ("SPLIT", &overlap("TOGGLE_PAUSE")),
+            ]
+        );
+        assert!(diagnostics.iter().all(|d| d.severity == Severity::Warning));
+
+        // So does a sequence ending on the key of a press binding
+        let mut shortcuts = AppConfig::default().shortcuts;
+        let split = shortcuts.get_mut("SPLIT").unwrap();
+        split.alt = false;
+        split.name = "BackQuote".to_string();
+        split.trigger = KeyTrigger::Sequence {
+            first: "KeyG".to_string(),
+            within_ms: DEFAULT_SEQUENCE_MS,
+        };
+        let diagnostics = validate_shortcuts(&shortcuts);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].action, "NEXT_RUN");
+        assert_eq!(diagnostics[0].issue, overlap("SPLIT"));
+
+        // Double taps of different keys do not overlap
+        let mut shortcuts = AppConfig::default().shortcuts;
+        shortcuts.get_mut("SPLIT").unwrap().trigger = KeyTrigger::DoubleTap { within_ms: 300 };
+        assert!(validate_shortcuts(&shortcuts).is_empty());
+    }
+
+    #[test]
+    fn mouse_buttons_are_bound_like_keys() {
+        assert_eq!(mouse_button_name(Button::Unknown(1)), Some(MOUSE_BACK));
+        assert_eq!(mouse_button_name(Button::Unknown(9)), Some(MOUSE_FORWARD));
+        assert_eq!(mouse_button_name(Button::Middle), Some(MOUSE_MIDDLE));
+        assert_eq!(mouse_button_name(Button::Left), None);
+        assert_eq!(wheel_name(-3), Some(WHEEL_DOWN));
+        assert_eq!(wheel_name(0), None);
+
+        let mut shortcuts = AppConfig::default().shortcuts;
+        let next_run = shortcuts.get_mut("NEXT_RUN").unwrap();
+        next_run.name = MOUSE_BACK.to_string();
+        next_run.shift = true;
+        let split = shortcuts.get_mut("SPLIT").unwrap();
+        split.name = WHEEL_DOWN.to_string();
+        split.trigger = KeyTrigger::Hold { hold_ms: 600 };
+
+        let diagnostics = validate_shortcuts(&shortcuts);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].action, "SPLIT");
+        assert_eq!(diagnostics[0].issue, ShortcutIssue::WheelHold);
+
+        let hotkeys = Hotkeys::default();
+        hotkeys.set_bindings(&shortcuts);
+        let mut back = key(MOUSE_BACK, false);
+        assert_eq!(tap(&hotkeys, back.clone()), None);
+        back.shift = true;
+        assert_eq!(tap(&hotkeys, back), Some(HotkeyAction::NextRun));
+    }
+}
@@ -0,0 +1,244 @@
+//! Item catalog embedded in the backend.
+//!
+//! The catalog is stored as `data/items.json` and parsed once on first use.
+//! It is the single source for the frontend's drop search (`search_items`),
+//! item lookups and the dashboard export (`get_items`).
+//!
+//! Each entry carries the pinyin initials of its Chinese name, generated
+//! offline, so items can be found by typing e.g. "hd" for 混沌.
+
+use crate::custom_items::{self, Qualities, CUSTOM_ITEM_PREFIX};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Raw catalog data, embedded at compile time.
+const ITEMS_JSON: &str = include_str!("../data/items.json");
+
+/// An entry of the item catalog, in the frontend's `ItemIndex` shape.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Item {
+    /// Unique item identifier, as stored in `RunRecord.drops`.
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// English item name.
+    pub name: String,
+    /// Chinese item name.
+    pub name_zh: String,
+    /// Raw rarity code from the catalog ("1", "2.0", "3", "4", ...).
+    pub rarity: String,
+    /// Display color for the item.
+    pub color: String,
+    /// Pinyin initials of `name_zh`, lowercase, with ASCII letters and
+    /// digits kept and punctuation dropped (e.g. "ae1" for "艾尔 (1#)").
+    #[serde(default)]
+    pub pinyin: String,
+}
+
+/// A catalog item as returned to the frontend, with its typed rarity.
+#[derive(Serialize, Clone, Debug)]
+pub struct ItemEntry {
+    #[serde(flatten)]
+    pub item: &'static Item,
+    /// Rarity class derived from the raw code and color.
+    pub rarity_class: RarityClass,
+}
+
+impl From<&'static Item> for ItemEntry {
+    fn from(item: &'static Item) -> Self {
+        Self {
+            item,
+            rarity_class: item.rarity_class(),
+        }
+    }
+}
+
+/// Rarity class of a dropped item, used for filtering and statistics.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum RarityClass {
+    Rune,
+    Runeword,
+    Unique,
+    Set,
+    Rare,
+    Magic,
+    Normal,
+}
+
+impl Item {
+    /// Classifies a catalog item.
+    ///
+    /// The catalog's raw `rarity` codes are inconsistent (uniques appear as
+    /// both "1" and "1.0"), so the color is checked first, as the dashboard does.
+    pub fn rarity_class(&self) -> RarityClass {
+        match self.color.to_lowercase().as_str() {
+            "#00ff00" => RarityClass::Set,
+            "#ffa500" if self.rarity == "4" => RarityClass::Rune,
+            "#ffa500" => RarityClass::Runeword,
+            _ => RarityClass::Unique,
+        }
+    }
+}
+
+/// Returns the full item catalog.
+pub fn catalog() -> &'static [Item] {
+    static CATALOG: OnceLock<Vec<Item>> = OnceLock::new();
+    CATALOG.get_or_init(|| serde_json::from_str(ITEMS_JSON).expect("embedded items.json is valid"))
+}
+
+/// Looks up a catalog item by ID.
+pub fn find(id: &str) -> Option<&'static Item> {
+    static INDEX: OnceLock<HashMap<&'static str, usize>> = OnceLock::new();
+    let index = INDEX.get_or_init(|| {
+        catalog()
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (item.id.as_str(), i))
+            .collect()
+    });
+    index.get(id).map(|&i| &catalog()[i])
+}
+
+/// Returns the rarity class of a drop ID.
+///
+/// Custom items are classified by their quality (normal, magic or rare);
+/// legacy `custom:<name>:<quality>` IDs by their quality suffix. Unknown IDs
+/// return `None`.
+///
+/// # Arguments
+/// * `drop_id` - The drop ID.
+/// * `qualities` - Custom item qualities, see [`custom_items::QualityIndex`].
+pub fn rarity_of(drop_id: &str, qualities: &Qualities) -> Option<RarityClass> {
+    if drop_id.starts_with(CUSTOM_ITEM_PREFIX) {
+        return qualities.get(drop_id).map(|q| q.rarity_class());
+    }
+    if let Some((_, quality)) = custom_items::parse_legacy(drop_id) {
+        return Some(quality.rarity_class());
+    }
+    find(drop_id).map(Item::rarity_class)
+}
+
+// ============================================================================
+// Search
+// ============================================================================
+
+/// How well an item matches a query; lower ranks first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchRank {
+    /// Chinese or English name or ID equals the query.
+    Exact,
+    /// Chinese name starts with the query.
+    ChinesePrefix,
+    /// Pinyin initials start with the query.
+    PinyinPrefix,
+    /// English name, or one of its words, starts with the query.
+    EnglishPrefix,
+    /// Chinese name contains the query.
+    ChineseContains,
+    /// Pinyin initials contain the query.
+    PinyinContains,
+    /// English name contains the query.
+    EnglishContains,
+}
+
+impl MatchRank {
+    /// Length of the name the rank was matched on, used to order matches of
+    /// the same rank so that the closest match comes first.
+    fn matched_len(self, item: &Item) -> usize {
+        match self {
+            Self::EnglishPrefix | Self::EnglishContains => item.name.chars().count(),
+            _ => item.name_zh.chars().count(),
+        }
+    }
+}
+
+/// Ranks an item against a trimmed, lowercased query.
+fn match_rank(item: &Item, query: &str) -> Option<MatchRank> {
+    let name_en = item.name.to_lowercase();
+    let is_pinyin_query = query.chars().all(|c| c.is_ascii_alphanumeric());
+
+    if item.name_zh == query || name_en == query || item.id == query {
+        Some(MatchRank::Exact)
+    } else if item.name_zh.starts_with(query) {
+        Some(MatchRank::ChinesePrefix)
+    } else if is_pinyin_query && item.pinyin.starts_with(query) {
+        Some(MatchRank::PinyinPrefix)
+    } else if name_en.starts_with(query)
+        || name_en
+            .split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .any(|word| word.starts_with(query))
+    {
+        Some(MatchRank::EnglishPrefix)
+    } else if item.name_zh.contains(query) {
+        Some(MatchRank::ChineseContains)
+    } else if is_pinyin_query && item.pinyin.contains(query) {
+        Some(MatchRank::PinyinContains)
+    } else if name_en.contains(query) {
+        Some(MatchRank::EnglishContains)
+    } else {
+        None
+    }
+}
+
+/// Searches the catalog by Chinese name, pinyin initials and English name.
+///
+/// Results are ordered by match quality (exact, then prefix matches, then
+/// substring matches), then by the length of the matched name, then catalog
+/// order.
+///
+/// # Arguments
+/// * `query` - The search text; case-insensitive.
+/// * `limit` - Maximum number of results.
+///
+/// # Returns
+/// The best matches, or nothing for a blank query.
+pub fn search(query: &str, limit: usize) -> Vec<&'static Item> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<(MatchRank, usize, &'static Item)> = catalog()
+        .iter()
+        .filter_map(|item| {
+            match_rank(item, &query).map(|rank| (rank, rank.matched_len(item), item))
+        })
+        .collect();
+    // Stable sort keeps catalog order among equal keys
+    matches.sort_by_key(|&(rank, len, _)| (rank, len));
+    matches
+        .into_iter()
+        .take(limit)
+        .map(|(_, _, item)| item)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(query: &str) -> Vec<&'static str> {
+        search(query, 5).iter().map(|i| i.id.as_str()).collect()
+    }
+
+    #[test]
+    fn every_item_has_pinyin_initials() {
+        assert!(catalog().iter().all(|i| !i.pinyin.is_empty()));
+        assert_eq!(find("r01").unwrap().pinyin, "ae1");
+    }
+
+    #[test]
+    fn search_ranks_exact_and_prefix_matches_first() {
+        let chaos = catalog().iter().find(|i| i.name_zh == "混沌").unwrap();
+        assert_eq!(ids("混沌")[0], chaos.id);
+        assert_eq!(ids("hd")[0], chaos.id);
+        assert_eq!(ids("Chaos")[0], chaos.id);
+
+        // "ber" matches the Ber rune's English name before any substring match
+        assert_eq!(ids("ber")[0], "r30");
+        assert!(search("  ", 5).is_empty());
+        assert!(search("zzzzzz", 5).is_empty());
+        assert_eq!(search("rune", 3).len(), 3);
+    }
+}
@@ -7,13 +7,32 @@
 
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod analytics;
+mod cache;
+mod checkpoint;
 mod commands;
+mod custom_items;
+mod db;
+mod error;
+mod hotkeys;
+mod items;
+mod migrations;
 mod models;
+mod scenes;
+mod segments;
+mod stats;
+mod timer;
 mod utils;
 
+use crate::cache::RunCache;
+use crate::custom_items::QualityIndex;
+use crate::db::Database;
+use crate::hotkeys::Hotkeys;
 use crate::models::KeyEventPayload;
-use crate::utils::{ensure_dir, AppState};
+use crate::timer::RunTimer;
+use crate::utils::{ensure_dir, read_config, AppState};
 use rdev::{listen, EventType, Key};
+use std::sync::RwLock;
 use std::thread;
 use tauri::{Emitter, Manager};
 
@@ -28,36 +47,58 @@ fn main() {
         .setup(|app| {
             // Initialize application data directory
             let app_dir = ensure_dir(app.handle());
+            let db = Database::open(&app_dir)?;
+            // A corrupted config is left in place for `get_config` to report
+            let config = read_config(&app_dir).unwrap_or_default();
+
+            let runs = RunCache::load(&db)?;
+            let qualities = QualityIndex::load(&db)?;
+            let hotkeys = Hotkeys::default();
+            hotkeys.set_bindings(&config.shortcuts);
             app.manage(AppState {
                 app_data_dir: app_dir,
+                db,
+                runs,
+                qualities,
+                timer: RunTimer::default(),
+                hotkeys,
+                config: RwLock::new(config),
             });
+            // Not fatal: the purge is retried, and reported, when the trash is listed
+            if let Err(e) = app.state::<AppState>().purge_expired_trash() {
+                eprintln!("Failed to purge the trash: {}", e);
+            }
+            timer::spawn_ticker(app.handle().clone());
 
             let handle = app.handle().clone();
+            let error_handle = app.handle().clone();
 
-            // Spawn global keyboard listener thread
-            // This enables shortcuts to work even when the app is not focused
+            // Spawn global keyboard and mouse listener thread
+            // This enables shortcuts to work even when the app is not focused;
+            // only bound actions reach the frontend, and none while recording a shortcut
             thread::spawn(move || {
                 let mut ctrl = false;
                 let mut alt = false;
                 let mut shift = false;
+                let mut meta = false;
 
-                let _ = listen(move |event| match event.event_type {
+                let result = listen(move |event| match event.event_type {
                     EventType::KeyPress(key) => match key {
                         // Track modifier key states
                         Key::ControlLeft | Key::ControlRight => ctrl = true,
                         Key::ShiftLeft | Key::ShiftRight => shift = true,
                         Key::Alt | Key::AltGr => alt = true,
-                        Key::MetaLeft | Key::MetaRight => {}
-                        // Emit key press events for regular keys
+                        Key::MetaLeft | Key::MetaRight => meta = true,
+                        // Match regular keys against the shortcut bindings
                         _ => {
-                            let key_name = format!("{:?}", key);
                             let payload = KeyEventPayload {
-                                name: key_name,
+                                name: format!("{:?}", key),
                                 ctrl,
                                 alt,
                                 shift,
+                                meta,
                             };
-                            let _ = handle.emit("global-key-press", payload);
+                            hotkeys::dispatch(&handle, payload);
                         }
                     },
                     EventType::KeyRelease(key) => match key {
@@ -65,10 +106,53 @@ fn main() {
                         Key::ControlLeft | Key::ControlRight => ctrl = false,
                         Key::ShiftLeft | Key::ShiftRight => shift = false,
                         Key::Alt | Key::AltGr => alt = false,
-                        _ => {}
+                        Key::MetaLeft | Key::MetaRight => meta = false,
+                        // Releases end hold bindings
+                        _ => hotkeys::dispatch_release(&handle, &format!("{:?}", key)),
                     },
+                    // Mouse buttons and the wheel are matched like keys
+                    EventType::ButtonPress(button) => {
+                        if let Some(name) = hotkeys::mouse_button_name(button) {
+                            let payload = KeyEventPayload {
+                                name: name.to_string(),
+                                ctrl,
+                                alt,
+                                shift,
+                                meta,
+                            };
+                            hotkeys::dispatch(&handle, payload);
+                        }
+                    }
+                    EventType::ButtonRelease(button) => {
+                        if let Some(name) = hotkeys::mouse_button_name(button) {
+                            hotkeys::dispatch_release(&handle, name);
+                        }
+                    }
+                    EventType::Wheel { delta_y, .. } => {
+                        if let Some(name) = hotkeys::wheel_name(delta_y) {
+                            let payload = KeyEventPayload {
+                                name: name.to_string(),
+                                ctrl,
+                                alt,
+                                shift,
+                                meta,
+                            };
+                            // A wheel step has no release; end it right away
+                            hotkeys::dispatch(&handle, payload);
+                            hotkeys::dispatch_release(&handle, name);
+                        }
+                    }
                     _ => {}
                 });
+
+                // E.g. no permission to observe input; the frontend then
+                // forwards keys pressed in its own window instead
+                if let Err(e) = result {
+                    let message = format!("{:?}", e);
+                    let state = error_handle.state::<AppState>();
+                    state.hotkeys.set_listener_error(message.clone());
+                    let _ = error_handle.emit(hotkeys::LISTENER_ERROR_EVENT, message);
+                }
             });
 
             // Show the main window
@@ -80,15 +164,64 @@ fn main() {
         .invoke_handler(tauri::generate_handler![
             // Run management
             commands::get_runs,
+            commands::get_run_timeline,
             commands::save_run,
+            commands::update_run,
             commands::delete_run,
             commands::clear_runs,
+            commands::check_legacy_import,
+            commands::delete_runs,
+            commands::retag_runs,
+            commands::set_runs_tz,
+            commands::get_trash,
+            commands::restore_run,
+            commands::empty_trash,
             commands::get_cloud_runs,
             commands::save_cloud_runs,
+            // Run timer
+            commands::timer_state,
+            commands::timer_start,
+            commands::timer_pause,
+            commands::timer_resume,
+            commands::timer_split,
+            commands::timer_set_drops,
+            commands::timer_set_tz,
+            commands::timer_finish,
+            commands::get_pending_run,
+            commands::restore_pending_run,
+            commands::discard_pending_run,
+            commands::get_scene_segments,
+            // Items
+            commands::get_items,
+            commands::search_items,
+            commands::get_custom_items,
+            commands::create_custom_item,
+            commands::update_custom_item,
+            commands::delete_custom_item,
+            commands::merge_custom_items,
+            // Scenes
+            commands::get_scenes,
+            commands::create_scene,
+            commands::update_scene,
+            commands::delete_scene,
+            // Sessions
+            commands::start_session,
+            commands::end_session,
+            commands::set_session_notes,
+            commands::get_sessions,
+            commands::get_session,
+            commands::delete_session,
+            commands::merge_sessions,
+            // Statistics
+            commands::get_stats,
+            commands::get_segment_stats,
+            commands::get_drop_rates,
+            commands::compare_drop_rates,
             // Configuration
             commands::get_config,
             commands::save_config,
             commands::reset_config,
+            commands::validate_shortcuts,
             // Window control
             commands::resize_window,
             commands::resize_window_custom,
@@ -97,6 +230,9 @@ fn main() {
             // Keyboard recording
             commands::start_record_key,
             commands::stop_record_key,
+            commands::dispatch_key,
+            commands::dispatch_key_release,
+            commands::get_hotkey_listener_error,
             // Dashboard
             commands::save_and_open_dashboard,
         ])
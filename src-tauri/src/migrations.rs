@@ -0,0 +1,765 @@
+//! Schema versions and migrations for persisted data.
+//!
+//! Every persisted store carries a schema version:
+//! - `config.json` stores it in the `schemaVersion` field.
+//! - `active_run.json` (the run checkpoint) stores it in the `schemaVersion` field.
+//! - `runs.db` stores it in SQLite's `user_version` pragma.
+//!
+//! Older payloads are upgraded on load by running each migration step in order,
+//! starting from the stored version. Files written by a newer build are rejected
+//! instead of being silently downgraded.
+
+use crate::custom_items::{self, CustomItem, CustomItemInfo, CustomQuality};
+use crate::error::{AppError, AppResult};
+use crate::scenes;
+use rusqlite::{params, Connection, Transaction};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+/// Current schema version of `config.json`.
+pub const CONFIG_SCHEMA_VERSION: u32 = 2;
+
+/// Current schema version of the run checkpoint.
+pub const CHECKPOINT_SCHEMA_VERSION: u32 = 1;
+
+/// A migration step upgrading a JSON payload by one version.
+type JsonMigration = fn(Value) -> AppResult<Value>;
+
+/// A migration step upgrading the run database by one version.
+type DbMigration = fn(&Transaction) -> AppResult<()>;
+
+/// Config migrations. Entry `i` upgrades version `i` to `i + 1`.
+const CONFIG_MIGRATIONS: &[JsonMigration] = &[config_v0_to_v1, config_v1_to_v2];
+
+/// Run checkpoint migrations. Entry `i` upgrades version `i` to `i + 1`.
+const CHECKPOINT_MIGRATIONS: &[JsonMigration] = &[checkpoint_v0_to_v1];
+
+/// Run database migrations. Entry `i` upgrades version `i` to `i + 1`.
+const RUNS_DB_MIGRATIONS: &[DbMigration] = &[
+    runs_db_v0_to_v1,
+    runs_db_v1_to_v2,
+    runs_db_v2_to_v3,
+    runs_db_v3_to_v4,
+    runs_db_v4_to_v5,
+    runs_db_v5_to_v6,
+    runs_db_v6_to_v7,
+    runs_db_v7_to_v8,
+];
+
+// ============================================================================
+// JSON stores
+// ============================================================================
+
+/// Returns the schema version stamped into a JSON payload.
+///
+/// Files written before versioning was introduced have no field and are version 0.
+pub fn schema_version(value: &Value) -> u32 {
+    value
+        .get("schemaVersion")
+        .and_then(Value::as_u64)
+        .map(|v| v as u32)
+        .unwrap_or(0)
+}
+
+/// Runs the migration steps a JSON payload is missing.
+///
+/// # Arguments
+/// * `value` - The payload as read from disk.
+/// * `file` - Name of the file, for error messages.
+/// * `current` - The schema version this build writes.
+/// * `steps` - Migrations, entry `i` upgrading version `i` to `i + 1`.
+fn migrate_json(
+    mut value: Value,
+    file: &str,
+    current: u32,
+    steps: &[JsonMigration],
+) -> AppResult<Value> {
+    let version = schema_version(&value);
+    if version > current {
+        return Err(AppError::UnsupportedVersion(format!(
+            "{} has schema version {}, this build supports up to {}",
+            file, version, current
+        )));
+    }
+
+    for step in &steps[version as usize..] {
+        value = step(value)?;
+    }
+    Ok(value)
+}
+
+// ============================================================================
+// Config
+// ============================================================================
+
+/// Upgrades a raw config payload to [`CONFIG_SCHEMA_VERSION`].
+///
+/// # Arguments
+/// * `value` - The config as read from disk.
+///
+/// # Returns
+/// The migrated payload, stamped with the current version.
+pub fn migrate_config(value: Value) -> AppResult<Value> {
+    migrate_json(
+        value,
+        "config.json",
+        CONFIG_SCHEMA_VERSION,
+        CONFIG_MIGRATIONS,
+    )
+}
+
+/// v0 → v1: fills in fields older or hand-edited configs may be missing.
+///
+/// Missing `language` defaults to Chinese, a missing `shortcuts` map becomes
+/// empty (and is populated with defaults on load), and each binding gets
+/// explicit modifier flags.
+fn config_v0_to_v1(value: Value) -> AppResult<Value> {
+    let mut config = into_object(value, "config.json")?;
+
+    config
+        .entry("language")
+        .or_insert_with(|| Value::String("CN".to_string()));
+
+    let shortcuts = config
+        .entry("shortcuts")
+        .or_insert_with(|| Value::Object(Map::new()));
+    if let Value::Object(bindings) = shortcuts {
+        for binding in bindings.values_mut() {
+            if let Value::Object(binding) = binding {
+                binding.entry("keycode").or_insert(Value::Null);
+                for modifier in ["alt", "ctrl", "shift"] {
+                    binding.entry(modifier).or_insert(Value::Bool(false));
+                }
+            }
+        }
+    } else {
+        *shortcuts = Value::Object(Map::new());
+    }
+
+    config.insert("schemaVersion".to_string(), json!(1));
+    Ok(Value::Object(config))
+}
+
+/// v1 → v2: keys `sceneSegments` by scene ID instead of legacy scene name.
+///
+/// If a scene has entries under both its name and its ID, the ID one is kept.
+fn config_v1_to_v2(value: Value) -> AppResult<Value> {
+    let mut config = into_object(value, "config.json")?;
+
+    if let Some(Value::Object(segments)) = config.get_mut("sceneSegments") {
+        let mut migrated = Map::new();
+        let (legacy, current): (Vec<_>, Vec<_>) = std::mem::take(segments)
+            .into_iter()
+            .partition(|(scene, _)| scenes::canonical_id(scene) != *scene);
+        for (scene, names) in current {
+            migrated.insert(scene, names);
+        }
+        for (scene, names) in legacy {
+            migrated
+                .entry(scenes::canonical_id(&scene))
+                .or_insert(names);
+        }
+        *segments = migrated;
+    }
+
+    config.insert("schemaVersion".to_string(), json!(2));
+    Ok(Value::Object(config))
+}
+
+// ============================================================================
+// Run checkpoint
+// ============================================================================
+
+/// Upgrades a raw run checkpoint to [`CHECKPOINT_SCHEMA_VERSION`].
+///
+/// # Arguments
+/// * `value` - The checkpoint as read from disk.
+///
+/// # Returns
+/// The migrated payload, stamped with the current version.
+pub fn migrate_checkpoint(value: Value) -> AppResult<Value> {
+    migrate_json(
+        value,
+        "active_run.json",
+        CHECKPOINT_SCHEMA_VERSION,
+        CHECKPOINT_MIGRATIONS,
+    )
+}
+
+/// v0 → v1: maps a legacy scene name to its scene ID.
+fn checkpoint_v0_to_v1(value: Value) -> AppResult<Value> {
+    let mut checkpoint = into_object(value, "active_run.json")?;
+
+    if let Some(Value::String(scene)) = checkpoint.get_mut("scene_id") {
+        *scene = scenes::canonical_id(scene);
+    }
+
+    checkpoint.insert("schemaVersion".to_string(), json!(1));
+    Ok(Value::Object(checkpoint))
+}
+
+// ============================================================================
+// Runs
+// ============================================================================
+
+/// Upgrades a run record from a legacy `runs.json` / `runs_cloud.json` file.
+///
+/// These files were never versioned. Records written by older frontends, or
+/// converted from cloud data, may lack `drops`, `is_tz`, `date_str` or `id`;
+/// this fills them in so the record deserializes into a current
+/// [`RunRecord`](crate::models::RunRecord).
+pub fn migrate_legacy_run(value: Value) -> AppResult<Value> {
+    let mut run = into_object(value, "legacy run record")?;
+
+    if !run.get("drops").is_some_and(Value::is_array) {
+        run.insert("drops".to_string(), json!([]));
+    }
+    run.entry("is_tz").or_insert(Value::Bool(false));
+    run.entry("id")
+        .or_insert_with(|| Value::String(uuid::Uuid::new_v4().to_string()));
+
+    if !run.contains_key("date_str") {
+        let date_str = run
+            .get("timestamp")
+            .and_then(Value::as_i64)
+            .and_then(chrono::DateTime::from_timestamp_millis)
+            .map(|t| t.format("%Y-%m-%d").to_string());
+        if let Some(date_str) = date_str {
+            run.insert("date_str".to_string(), Value::String(date_str));
+        }
+    }
+
+    Ok(Value::Object(run))
+}
+
+/// Brings the run database schema up to date.
+///
+/// Each pending step runs in its own transaction together with the
+/// `user_version` bump, so an interrupted upgrade resumes where it stopped.
+pub fn migrate_runs_db(conn: &mut Connection) -> AppResult<()> {
+    let version: u32 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    if version as usize > RUNS_DB_MIGRATIONS.len() {
+        return Err(AppError::UnsupportedVersion(format!(
+            "runs.db has schema version {}, this build supports up to {}",
+            version,
+            RUNS_DB_MIGRATIONS.len()
+        )));
+    }
+
+    for (index, step) in RUNS_DB_MIGRATIONS.iter().enumerate().skip(version as usize) {
+        let tx = conn.transaction()?;
+        step(&tx)?;
+        tx.pragma_update(None, "user_version", index + 1)?;
+        tx.commit()?;
+    }
+    Ok(())
+}
+
+/// v0 → v1: initial schema with run, cloud cache and meta tables.
+///
+/// Uses `IF NOT EXISTS` because databases created before versioning already
+/// have these tables at version 0.
+fn runs_db_v0_to_v1(tx: &Transaction) -> AppResult<()> {
+    tx.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS runs (
+            id          TEXT PRIMARY KEY,
+            timestamp   INTEGER NOT NULL,
+            date_str    TEXT NOT NULL,
+            scene_id    TEXT NOT NULL,
+            duration_ms INTEGER NOT NULL,
+            drops       TEXT NOT NULL,
+            is_tz       INTEGER NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_runs_date_str ON runs(date_str);
+        CREATE INDEX IF NOT EXISTS idx_runs_scene_id ON runs(scene_id);
+        CREATE INDEX IF NOT EXISTS idx_runs_timestamp ON runs(timestamp);
+
+        CREATE TABLE IF NOT EXISTS cloud_runs (
+            id          TEXT PRIMARY KEY,
+            timestamp   INTEGER NOT NULL,
+            date_str    TEXT NOT NULL,
+            scene_id    TEXT NOT NULL,
+            duration_ms INTEGER NOT NULL,
+            drops       TEXT NOT NULL,
+            is_tz       INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS meta (
+            key   TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );
+        ",
+    )?;
+    Ok(())
+}
+
+/// v1 → v2: adds the per-run edit history.
+fn runs_db_v1_to_v2(tx: &Transaction) -> AppResult<()> {
+    tx.execute_batch(
+        "
+        ALTER TABLE runs ADD COLUMN edits TEXT NOT NULL DEFAULT '[]';
+        ALTER TABLE cloud_runs ADD COLUMN edits TEXT NOT NULL DEFAULT '[]';
+        ",
+    )?;
+    Ok(())
+}
+
+/// v2 → v3: adds the trash table for soft-deleted runs.
+fn runs_db_v2_to_v3(tx: &Transaction) -> AppResult<()> {
+    tx.execute_batch(
+        "
+        CREATE TABLE trash (
+            id          TEXT PRIMARY KEY,
+            timestamp   INTEGER NOT NULL,
+            date_str    TEXT NOT NULL,
+            scene_id    TEXT NOT NULL,
+            duration_ms INTEGER NOT NULL,
+            drops       TEXT NOT NULL,
+            is_tz       INTEGER NOT NULL,
+            edits       TEXT NOT NULL DEFAULT '[]',
+            deleted_at  INTEGER NOT NULL
+        );
+        CREATE INDEX idx_trash_deleted_at ON trash(deleted_at);
+        ",
+    )?;
+    Ok(())
+}
+
+/// v3 → v4: adds sessions and the `session_id` of every run.
+fn runs_db_v3_to_v4(tx: &Transaction) -> AppResult<()> {
+    tx.execute_batch(
+        "
+        CREATE TABLE sessions (
+            id          TEXT PRIMARY KEY,
+            scene_id    TEXT NOT NULL,
+            started_at  INTEGER NOT NULL,
+            ended_at    INTEGER,
+            is_tz       INTEGER NOT NULL,
+            notes       TEXT NOT NULL DEFAULT ''
+        );
+        CREATE INDEX idx_sessions_started_at ON sessions(started_at);
+
+        ALTER TABLE runs ADD COLUMN session_id TEXT;
+        ALTER TABLE cloud_runs ADD COLUMN session_id TEXT;
+        ALTER TABLE trash ADD COLUMN session_id TEXT;
+        CREATE INDEX idx_runs_session_id ON runs(session_id);
+        ",
+    )?;
+    Ok(())
+}
+
+/// v4 → v5: adds the per-run event timeline.
+fn runs_db_v4_to_v5(tx: &Transaction) -> AppResult<()> {
+    tx.execute_batch(
+        "
+        ALTER TABLE runs ADD COLUMN timeline TEXT NOT NULL DEFAULT '[]';
+        ALTER TABLE cloud_runs ADD COLUMN timeline TEXT NOT NULL DEFAULT '[]';
+        ALTER TABLE trash ADD COLUMN timeline TEXT NOT NULL DEFAULT '[]';
+        ",
+    )?;
+    Ok(())
+}
+
+/// v5 → v6: adds the per-run segment durations.
+fn runs_db_v5_to_v6(tx: &Transaction) -> AppResult<()> {
+    tx.execute_batch(
+        "
+        ALTER TABLE runs ADD COLUMN segments TEXT NOT NULL DEFAULT '[]';
+        ALTER TABLE cloud_runs ADD COLUMN segments TEXT NOT NULL DEFAULT '[]';
+        ALTER TABLE trash ADD COLUMN segments TEXT NOT NULL DEFAULT '[]';
+        ",
+    )?;
+    Ok(())
+}
+
+/// v6 → v7: adds custom scenes and replaces legacy scene names with scene IDs.
+fn runs_db_v6_to_v7(tx: &Transaction) -> AppResult<()> {
+    tx.execute_batch(
+        "
+        CREATE TABLE scenes (
+            id          TEXT PRIMARY KEY,
+            name_zh     TEXT NOT NULL,
+            name_en     TEXT NOT NULL,
+            label_zh    TEXT NOT NULL,
+            label_en    TEXT NOT NULL,
+            act         INTEGER,
+            area        TEXT,
+            segments    TEXT NOT NULL DEFAULT '[]'
+        );
+        ",
+    )?;
+    remap_legacy_scene_ids(tx)
+}
+
+/// Replaces the Chinese scene names used as `scene_id` before the scene
+/// registry with the IDs of the matching built-in scenes.
+fn remap_legacy_scene_ids(tx: &Transaction) -> AppResult<()> {
+    for table in ["runs", "cloud_runs", "trash", "sessions"] {
+        let mut stmt = tx.prepare(&format!(
+            "UPDATE {table} SET scene_id = ?1 WHERE scene_id = ?2"
+        ))?;
+        for scene in scenes::builtin() {
+            stmt.execute(params![scene.id, scene.info.name_zh])?;
+        }
+    }
+    Ok(())
+}
+
+/// v7 → v8: adds custom items and replaces legacy `custom:<name>:<quality>`
+/// drop IDs with references to them.
+fn runs_db_v7_to_v8(tx: &Transaction) -> AppResult<()> {
+    tx.execute_batch(
+        "
+        CREATE TABLE custom_items (
+            id          TEXT PRIMARY KEY,
+            name_zh     TEXT NOT NULL,
+            name_en     TEXT NOT NULL,
+            quality     INTEGER NOT NULL,
+            base_type   TEXT,
+            notes       TEXT NOT NULL DEFAULT '',
+            created_at  INTEGER NOT NULL
+        );
+        ",
+    )?;
+    convert_legacy_custom_drops(tx)
+}
+
+/// Upgrades runs imported from the legacy JSON files, which skip the database
+/// migrations, to the current conventions for scene and custom item IDs.
+pub fn upgrade_imported_runs(tx: &Transaction) -> AppResult<()> {
+    remap_legacy_scene_ids(tx)?;
+    convert_legacy_custom_drops(tx)
+}
+
+/// Creates one custom item per distinct legacy custom drop (same name and
+/// quality) and rewrites every reference to it: drops, timeline events and
+/// edit history of local, cloud and trashed runs.
+///
+/// Existing custom items with the same name and quality are reused, so the
+/// conversion can run again after a legacy import.
+fn convert_legacy_custom_drops(tx: &Transaction) -> AppResult<()> {
+    let mut ids: HashMap<(String, CustomQuality), String> = HashMap::new();
+    {
+        let mut stmt = tx.prepare("SELECT id, name_en, quality FROM custom_items")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+        for row in rows {
+            let (id, name, quality): (String, String, u8) = row?;
+            ids.insert((name, CustomQuality::from_code(quality)), id);
+        }
+    }
+    let created_at = chrono::Utc::now().timestamp_millis();
+    let mut new_items = Vec::new();
+    let mut convert = |drop_id: &str| {
+        let key = custom_items::parse_legacy(drop_id)?;
+        let id = ids.entry(key).or_insert_with_key(|(name, quality)| {
+            let item = CustomItem::new(CustomItemInfo::named(name, *quality), created_at);
+            let id = item.id.clone();
+            new_items.push(item);
+            id
+        });
+        Some(id.clone())
+    };
+
+    // Legacy IDs only appear as JSON strings, i.e. right after a quote
+    let needle = format!("\"{}", custom_items::LEGACY_CUSTOM_PREFIX);
+    for table in ["runs", "cloud_runs", "trash"] {
+        let rows: Vec<(String, [String; 3])> = {
+            let mut stmt = tx.prepare(&format!(
+                "SELECT id, drops, timeline, edits FROM {table} \
+                 WHERE instr(drops || timeline || edits, ?1) > 0"
+            ))?;
+            let rows = stmt.query_map(params![needle], |row| {
+                Ok((row.get(0)?, [row.get(1)?, row.get(2)?, row.get(3)?]))
+            })?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+
+        let mut update = tx.prepare(&format!(
+            "UPDATE {table} SET drops = ?2, timeline = ?3, edits = ?4 WHERE id = ?1"
+        ))?;
+        for (id, columns) in rows {
+            let mut converted = Vec::with_capacity(columns.len());
+            for text in columns {
+                let mut value: Value = serde_json::from_str(&text)?;
+                map_strings(&mut value, &mut convert);
+                converted.push(serde_json::to_string(&value)?);
+            }
+            update.execute(params![id, converted[0], converted[1], converted[2]])?;
+        }
+    }
+
+    let mut insert = tx.prepare(
+        "INSERT INTO custom_items (id, name_zh, name_en, quality, base_type, notes, created_at) \
+         VALUES (?1, ?2, ?3, ?4, NULL, '', ?5)",
+    )?;
+    for item in new_items {
+        insert.execute(params![
+            item.id,
+            item.info.name_zh,
+            item.info.name_en,
+            item.info.quality.code(),
+            item.created_at
+        ])?;
+    }
+    Ok(())
+}
+
+/// Replaces every string inside a JSON value for which `f` returns a
+/// replacement.
+fn map_strings(value: &mut Value, f: &mut impl FnMut(&str) -> Option<String>) {
+    match value {
+        Value::String(s) => {
+            if let Some(replacement) = f(s) {
+                *s = replacement;
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(|v| map_strings(v, f)),
+        Value::Object(map) => map.values_mut().for_each(|v| map_strings(v, f)),
+        _ => {}
+    }
+}
+
+/// Unwraps a JSON object, rejecting any other payload shape.
+fn into_object(value: Value, what: &str) -> AppResult<Map<String, Value>> {
+    match value {
+        Value::Object(map) => Ok(map),
+        other => Err(AppError::Serialization(format!(
+            "{} must be a JSON object, found {}",
+            what, other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AppConfig, RunCheckpoint, RunRecord};
+
+    const CONFIG_V0: &str = include_str!("../tests/fixtures/config_v0.json");
+    const CONFIG_V0_MINIMAL: &str = include_str!("../tests/fixtures/config_v0_minimal.json");
+    const CONFIG_V1: &str = include_str!("../tests/fixtures/config_v1.json");
+    const CONFIG_V2: &str = include_str!("../tests/fixtures/config_v2.json");
+    const RUNS_V0: &str = include_str!("../tests/fixtures/runs_v0.json");
+
+    fn load_config(fixture: &str) -> AppConfig {
+        let value = serde_json::from_str(fixture).unwrap();
+        serde_json::from_value(migrate_config(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn config_v0_is_upgraded_and_keeps_settings() {
+        let config = load_config(CONFIG_V0);
+
+        assert_eq!(config.schema_version, CONFIG_SCHEMA_VERSION);
+        assert_eq!(config.language, "EN");
+        assert_eq!(config.theme.as_deref(), Some("diablo-fire"));
+        assert_eq!(config.theme_opacity, Some(85));
+        assert_eq!(config.shortcuts["NEXT_RUN"].name, "BackQuote");
+        assert!(config.shortcuts["TOGGLE_PAUSE"].alt);
+        assert_eq!(config.last_sync_time, Some(json!(1717000000000i64)));
+        assert_eq!(config.custom_view_sizes.unwrap()["HOME"].w, 340);
+    }
+
+    #[test]
+    fn config_v0_minimal_gets_defaults() {
+        let config = load_config(CONFIG_V0_MINIMAL);
+
+        assert_eq!(config.schema_version, CONFIG_SCHEMA_VERSION);
+        assert_eq!(config.language, "CN");
+        let binding = &config.shortcuts["NEXT_RUN"];
+        assert_eq!(binding.name, "KeyN");
+        assert!(binding.keycode.is_none());
+        assert!(!binding.alt && !binding.ctrl && !binding.shift);
+    }
+
+    #[test]
+    fn config_v1_scene_segments_are_keyed_by_id() {
+        let mut value: Value = serde_json::from_str(CONFIG_V1).unwrap();
+        value["sceneSegments"] = json!({
+            "泰摩高地-地穴": ["Level 1", "Level 2"],
+            "countess": ["Tower"],
+            "遗忘高塔": ["Stale"],
+            "my-scene": ["Custom"]
+        });
+        let config: AppConfig = serde_json::from_value(migrate_config(value).unwrap()).unwrap();
+
+        let segments = config.scene_segments.unwrap();
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments["the_pit"], ["Level 1", "Level 2"]);
+        assert_eq!(segments["countess"], ["Tower"]);
+        assert_eq!(segments["my-scene"], ["Custom"]);
+    }
+
+    #[test]
+    fn config_v2_is_unchanged() {
+        let value: Value = serde_json::from_str(CONFIG_V2).unwrap();
+        assert_eq!(migrate_config(value.clone()).unwrap(), value);
+    }
+
+    #[test]
+    fn config_from_newer_build_is_rejected() {
+        let value = json!({ "schemaVersion": CONFIG_SCHEMA_VERSION + 1 });
+        assert!(matches!(
+            migrate_config(value),
+            Err(AppError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn checkpoint_v0_is_upgraded() {
+        let value = json!({
+            "scene_id": "泰摩高地-地穴",
+            "is_tz": false,
+            "started_at": 1_000,
+            "elapsed_ms": 4_000,
+            "saved_at": 5_000
+        });
+        let checkpoint: RunCheckpoint =
+            serde_json::from_value(migrate_checkpoint(value).unwrap()).unwrap();
+
+        assert_eq!(checkpoint.schema_version, CHECKPOINT_SCHEMA_VERSION);
+        assert_eq!(checkpoint.scene_id, "the_pit");
+        assert!(checkpoint.run_id.is_none());
+    }
+
+    #[test]
+    fn checkpoint_from_newer_build_is_rejected() {
+        let value = json!({ "schemaVersion": CHECKPOINT_SCHEMA_VERSION + 1 });
+        assert!(matches!(
+            migrate_checkpoint(value),
+            Err(AppError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn legacy_runs_are_upgraded() {
+        let values: Vec<Value> = serde_json::from_str(RUNS_V0).unwrap();
+        let runs: Vec<RunRecord> = values
+            .into_iter()
+            .map(|v| serde_json::from_value(migrate_legacy_run(v).unwrap()).unwrap())
+            .collect();
+
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].drops, vec!["r30".to_string()]);
+        assert!(runs[0].is_tz);
+        assert!(runs[1].drops.is_empty());
+        assert!(!runs[1].is_tz);
+        assert_eq!(runs[2].date_str, "2024-05-29");
+        assert!(!runs[2].id.is_empty());
+    }
+
+    #[test]
+    fn runs_db_is_migrated_to_latest() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate_runs_db(&mut conn).unwrap();
+        // Running again on an up-to-date database is a no-op
+        migrate_runs_db(&mut conn).unwrap();
+
+        let version: usize = conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, RUNS_DB_MIGRATIONS.len());
+    }
+
+    #[test]
+    fn legacy_scene_names_are_mapped_to_ids() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate_runs_db(&mut conn).unwrap();
+        conn.execute_batch(
+            "
+            INSERT INTO runs (id, timestamp, date_str, scene_id, duration_ms, drops, is_tz)
+            VALUES ('a', 0, '2024-06-01', '混沌避难所', 1000, '[]', 0),
+                   ('b', 0, '2024-06-01', 'My Scene', 1000, '[]', 0);
+            INSERT INTO sessions (id, scene_id, started_at, is_tz)
+            VALUES ('s', '世界之石', 0, 0);
+            ",
+        )
+        .unwrap();
+
+        let tx = conn.transaction().unwrap();
+        remap_legacy_scene_ids(&tx).unwrap();
+        tx.commit().unwrap();
+
+        let scene_of = |sql: &str| -> String { conn.query_row(sql, [], |row| row.get(0)).unwrap() };
+        assert_eq!(
+            scene_of("SELECT scene_id FROM runs WHERE id = 'a'"),
+            "chaos"
+        );
+        assert_eq!(
+            scene_of("SELECT scene_id FROM runs WHERE id = 'b'"),
+            "My Scene"
+        );
+        assert_eq!(scene_of("SELECT scene_id FROM sessions"), "baal");
+    }
+
+    #[test]
+    fn legacy_custom_drops_become_custom_items() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate_runs_db(&mut conn).unwrap();
+        conn.execute_batch(
+            r#"
+            INSERT INTO runs (id, timestamp, date_str, scene_id, duration_ms, drops, is_tz,
+                              timeline, edits)
+            VALUES ('a', 0, '2024-06-01', 'chaos', 1000,
+                    '["r30","custom:Jewel:2","custom:Rare: Amulet:3"]', 0,
+                    '[{"at":0,"offset_ms":0,"kind":"drop","item_id":"custom:Jewel:2"}]',
+                    '[{"edited_at":0,"previous":{"drops":["custom:Jewel:2"]}}]'),
+                   ('b', 0, '2024-06-01', 'chaos', 1000, '[]', 0, '[]', '[]');
+            INSERT INTO trash (id, timestamp, date_str, scene_id, duration_ms, drops, is_tz,
+                               deleted_at)
+            VALUES ('c', 0, '2024-06-01', 'chaos', 1000, '["custom:Jewel:2"]', 0, 0);
+            "#,
+        )
+        .unwrap();
+
+        let tx = conn.transaction().unwrap();
+        convert_legacy_custom_drops(&tx).unwrap();
+        // Converting again finds nothing left to convert
+        convert_legacy_custom_drops(&tx).unwrap();
+        tx.commit().unwrap();
+
+        let items: Vec<(String, String, u8)> = conn
+            .prepare("SELECT id, name_en, quality FROM custom_items ORDER BY name_en")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        let (jewel, amulet) = (&items[0], &items[1]);
+        assert_eq!((jewel.1.as_str(), jewel.2), ("Jewel", 2));
+        assert_eq!((amulet.1.as_str(), amulet.2), ("Rare: Amulet", 3));
+
+        let column = |sql: &str| -> String { conn.query_row(sql, [], |row| row.get(0)).unwrap() };
+        let drops: Vec<String> =
+            serde_json::from_str(&column("SELECT drops FROM runs WHERE id = 'a'")).unwrap();
+        assert_eq!(
+            drops,
+            vec!["r30".to_string(), jewel.0.clone(), amulet.0.clone()]
+        );
+        for sql in [
+            "SELECT timeline FROM runs WHERE id = 'a'",
+            "SELECT edits FROM runs WHERE id = 'a'",
+            "SELECT drops FROM trash",
+        ] {
+            let text = column(sql);
+            assert!(
+                text.contains(&jewel.0) && !text.contains("custom:"),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn runs_db_from_newer_build_is_rejected() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "user_version", RUNS_DB_MIGRATIONS.len() + 1)
+            .unwrap();
+        assert!(matches!(
+            migrate_runs_db(&mut conn),
+            Err(AppError::UnsupportedVersion(_))
+        ));
+    }
+}
@@ -3,6 +3,11 @@
 //! This module defines all the data structures used for serialization,
 //! configuration, and communication between the frontend and backend.
 
+use crate::custom_items::Qualities;
+use crate::error::{AppError, AppResult};
+use crate::items::{self, RarityClass};
+use crate::migrations::CONFIG_SCHEMA_VERSION;
+use crate::stats::DurationStats;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -12,8 +17,9 @@ use std::collections::HashMap;
 
 /// Represents a keyboard shortcut binding.
 ///
-/// Stores the key identifier along with modifier key states (Alt, Ctrl, Shift).
-#[derive(Serialize, Deserialize, Clone, Debug)]
+/// Stores the key identifier along with modifier key states (Alt, Ctrl, Shift,
+/// Meta).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct KeyBinding {
     /// Optional Windows virtual key code.
     pub keycode: Option<u32>,
@@ -23,8 +29,32 @@ pub struct KeyBinding {
     pub ctrl: bool,
     /// Whether Shift key is required.
     pub shift: bool,
-    /// The key name as returned by rdev (e.g., "KeyD", "Return").
+    /// Whether the Meta (Windows/Command) key is required.
+    #[serde(default)]
+    pub meta: bool,
+    /// The key name as returned by rdev (e.g., "KeyD", "Return"), or a mouse
+    /// button (e.g., "MouseBack", see [`crate::hotkeys`]).
     pub name: String,
+    /// How the key has to be pressed.
+    #[serde(default)]
+    pub trigger: KeyTrigger,
+}
+
+/// How the key of a [`KeyBinding`] has to be pressed to fire it.
+///
+/// Timings are in milliseconds. Modifiers apply to every key press involved.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum KeyTrigger {
+    /// A single press (auto-repeat fires again while held).
+    #[default]
+    Press,
+    /// The `first` key, then the bound key within `within_ms`.
+    Sequence { first: String, within_ms: u64 },
+    /// The key pressed twice within `within_ms`.
+    DoubleTap { within_ms: u64 },
+    /// The key held down for `hold_ms`.
+    Hold { hold_ms: u64 },
 }
 
 /// Application configuration stored persistently.
@@ -32,6 +62,9 @@ pub struct KeyBinding {
 /// Contains user preferences such as language, theme, and keyboard shortcuts.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AppConfig {
+    /// Schema version of the persisted file, see [`crate::migrations`].
+    #[serde(rename = "schemaVersion", default)]
+    pub schema_version: u32,
     /// UI language code ("CN" for Chinese, "EN" for English).
     pub language: String,
     /// Theme identifier (e.g., "dark-transparent", "diablo-fire").
@@ -51,12 +84,23 @@ pub struct AppConfig {
     /// Cloud configuration and user info.
     /// Stored as a raw JSON value to be flexible with frontend structure.
     pub cloud: Option<serde_json::Value>,
+    /// Days a deleted run stays in the trash before it is purged.
+    /// `None` uses [`DEFAULT_TRASH_RETENTION_DAYS`].
+    #[serde(rename = "trashRetentionDays", default)]
+    pub trash_retention_days: Option<u32>,
     /// User-customized window sizes per view.
     /// Keys are view names (e.g., "HOME", "SETTINGS"), values are {w, h} objects.
     #[serde(rename = "customViewSizes")]
     pub custom_view_sizes: Option<HashMap<String, ViewSize>>,
+    /// Segment names per scene identifier, overriding the built-in ones.
+    /// See [`crate::segments`].
+    #[serde(rename = "sceneSegments", default)]
+    pub scene_segments: Option<HashMap<String, Vec<String>>>,
 }
 
+/// Default number of days deleted runs are kept in the trash.
+pub const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
 /// Window size dimensions.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ViewSize {
@@ -77,7 +121,9 @@ impl Default for AppConfig {
                 alt: false,
                 ctrl: false,
                 shift: false,
+                meta: false,
                 name: "BackQuote".to_string(),
+                trigger: KeyTrigger::Press,
             },
         );
 
@@ -89,7 +135,9 @@ impl Default for AppConfig {
                 alt: true,
                 ctrl: false,
                 shift: false,
+                meta: false,
                 name: "KeyP".to_string(),
+                trigger: KeyTrigger::Press,
             },
         );
 
@@ -101,7 +149,23 @@ impl Default for AppConfig {
                 alt: true,
                 ctrl: false,
                 shift: false,
+                meta: false,
                 name: "KeyD".to_string(),
+                trigger: KeyTrigger::Press,
+            },
+        );
+
+        // Default shortcut: Alt+S to mark a split
+        shortcuts.insert(
+            "SPLIT".to_string(),
+            KeyBinding {
+                keycode: None,
+                alt: true,
+                ctrl: false,
+                shift: false,
+                meta: false,
+                name: "KeyS".to_string(),
+                trigger: KeyTrigger::Press,
             },
         );
 
@@ -113,11 +177,14 @@ impl Default for AppConfig {
                 alt: true,
                 ctrl: false,
                 shift: false,
+                meta: false,
                 name: "Return".to_string(),
+                trigger: KeyTrigger::Press,
             },
         );
 
         Self {
+            schema_version: CONFIG_SCHEMA_VERSION,
             language: "CN".to_string(),
             theme: None,
             premium_unlocked: None,
@@ -125,7 +192,9 @@ impl Default for AppConfig {
             shortcuts,
             last_sync_time: None,
             cloud: None,
+            trash_retention_days: None,
             custom_view_sizes: None,
+            scene_segments: None,
         }
     }
 }
@@ -134,10 +203,11 @@ impl Default for AppConfig {
 // Event Payloads
 // ============================================================================
 
-/// Payload for global keyboard events emitted to the frontend.
-#[derive(Clone, Serialize)]
+/// A key press passed to the shortcut matcher.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct KeyEventPayload {
-    /// The key name as returned by rdev.
+    /// The key name as returned by rdev, or a browser key name (see
+    /// [`crate::hotkeys::normalize_key`]).
     pub name: String,
     /// Whether Alt key was pressed.
     pub alt: bool,
@@ -145,6 +215,9 @@ pub struct KeyEventPayload {
     pub ctrl: bool,
     /// Whether Shift key was pressed.
     pub shift: bool,
+    /// Whether the Meta (Windows/Command) key was pressed.
+    #[serde(default)]
+    pub meta: bool,
 }
 
 // ============================================================================
@@ -162,7 +235,7 @@ pub struct RunRecord {
     pub timestamp: i64,
     /// Human-readable date string (YYYY-MM-DD format).
     pub date_str: String,
-    /// Scene identifier (e.g., "the_pit", "countess").
+    /// Scene identifier (e.g., "the_pit", "countess"), see [`crate::scenes`].
     pub scene_id: String,
     /// Run duration in milliseconds.
     pub duration_ms: i64,
@@ -170,12 +243,392 @@ pub struct RunRecord {
     pub drops: Vec<String>,
     /// Whether this was a Terror Zone run.
     pub is_tz: bool,
+    /// Edits made after the run was saved, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edits: Vec<RunEdit>,
+    /// The session this run was recorded in, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// Events recorded while the run was timed, in order.
+    ///
+    /// Empty for runs saved before timelines were recorded; see
+    /// [`RunTimeline::for_run`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub timeline: Vec<RunEvent>,
+    /// Durations of the run's named segments, in order; empty if the run
+    /// was not split.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<SegmentTime>,
+}
+
+impl RunRecord {
+    /// Applies a patch and records the previous values in the edit history.
+    ///
+    /// Fields whose new value equals the current one are ignored.
+    ///
+    /// # Arguments
+    /// * `patch` - The fields to change.
+    /// * `edited_at` - Unix timestamp (milliseconds) of the edit.
+    ///
+    /// # Returns
+    /// `true` if any field changed.
+    pub fn apply_patch(&mut self, patch: &RunPatch, edited_at: i64) -> bool {
+        let mut previous = RunPatch::default();
+
+        if let Some(ref drops) = patch.drops {
+            if drops != &self.drops {
+                previous.drops = Some(std::mem::replace(&mut self.drops, drops.clone()));
+            }
+        }
+        if let Some(ref scene_id) = patch.scene_id {
+            if scene_id != &self.scene_id {
+                previous.scene_id = Some(std::mem::replace(&mut self.scene_id, scene_id.clone()));
+            }
+        }
+        if let Some(is_tz) = patch.is_tz {
+            if is_tz != self.is_tz {
+                previous.is_tz = Some(std::mem::replace(&mut self.is_tz, is_tz));
+            }
+        }
+        if let Some(duration_ms) = patch.duration_ms {
+            if duration_ms != self.duration_ms {
+                previous.duration_ms = Some(std::mem::replace(&mut self.duration_ms, duration_ms));
+            }
+        }
+
+        if previous.is_empty() {
+            return false;
+        }
+        self.edits.push(RunEdit {
+            edited_at,
+            previous,
+        });
+        true
+    }
+
+    /// Replaces every reference to a drop ID, in the drops, the timeline and
+    /// the edit history, without recording an edit.
+    ///
+    /// Used when custom items are merged: the drop itself is unchanged, only
+    /// the item it refers to.
+    ///
+    /// # Returns
+    /// `true` if any reference was replaced.
+    pub fn replace_drop_id(&mut self, from: &str, to: &str) -> bool {
+        let previous_drops = self.edits.iter_mut().flat_map(|e| &mut e.previous.drops);
+        let timeline_drops = self.timeline.iter_mut().filter_map(|e| match &mut e.kind {
+            RunEventKind::Drop { item_id } => Some(item_id),
+            _ => None,
+        });
+
+        let mut replaced = false;
+        for id in self
+            .drops
+            .iter_mut()
+            .chain(previous_drops.flatten())
+            .chain(timeline_drops)
+            .filter(|id| *id == from)
+        {
+            *id = to.to_string();
+            replaced = true;
+        }
+        replaced
+    }
+}
+
+/// Minimum valid run duration in milliseconds, matching the frontend's `MIN_RUN_DURATION_MS`.
+pub const MIN_RUN_DURATION_MS: i64 = 100;
+
+/// A partial update to a saved run. Unset fields are left unchanged.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RunPatch {
+    /// Replacement drop list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drops: Option<Vec<String>>,
+    /// Replacement scene identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scene_id: Option<String>,
+    /// Replacement Terror Zone flag.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_tz: Option<bool>,
+    /// Replacement duration in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+}
+
+impl RunPatch {
+    /// Returns whether the patch sets no field.
+    pub fn is_empty(&self) -> bool {
+        self == &RunPatch::default()
+    }
+
+    /// Checks that every set field holds a value a run could have been saved with.
+    pub fn validate(&self) -> AppResult<()> {
+        if let Some(ref drops) = self.drops {
+            if drops.iter().any(|d| d.trim().is_empty()) {
+                return Err(AppError::Validation("drop IDs must not be empty".into()));
+            }
+        }
+        if let Some(ref scene_id) = self.scene_id {
+            if scene_id.trim().is_empty() {
+                return Err(AppError::Validation("scene_id must not be empty".into()));
+            }
+        }
+        if let Some(duration_ms) = self.duration_ms {
+            if duration_ms < MIN_RUN_DURATION_MS {
+                return Err(AppError::Validation(format!(
+                    "duration_ms must be at least {}",
+                    MIN_RUN_DURATION_MS
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One entry in a run's edit history.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RunEdit {
+    /// Unix timestamp (milliseconds) when the edit was made.
+    pub edited_at: i64,
+    /// Values of the changed fields before the edit.
+    pub previous: RunPatch,
+}
+
+/// A deleted run waiting in the trash.
+#[derive(Serialize, Clone, Debug)]
+pub struct TrashedRun {
+    /// The deleted run.
+    #[serde(flatten)]
+    pub run: RunRecord,
+    /// Unix timestamp (milliseconds) when the run was deleted.
+    pub deleted_at: i64,
+}
+
+/// A pause within a run, as Unix timestamps (milliseconds).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PauseInterval {
+    /// When the pause started.
+    pub start: i64,
+    /// When the pause ended; `None` while still paused.
+    pub end: Option<i64>,
+}
+
+/// Duration of one named segment of a run.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SegmentTime {
+    /// Segment name, see [`crate::segments`].
+    pub name: String,
+    /// Pause-adjusted duration in milliseconds.
+    pub duration_ms: i64,
+}
+
+/// What happened at a point of a run's timeline.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RunEventKind {
+    /// The timer started.
+    Start,
+    /// The run was paused.
+    Pause,
+    /// The run was resumed.
+    Resume,
+    /// An item was recorded as dropped.
+    Drop { item_id: String },
+    /// A split was recorded.
+    Split,
+    /// The timer stopped and the run was saved.
+    Finish,
+}
+
+/// One entry of a run's timeline.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RunEvent {
+    /// Unix timestamp (milliseconds) of the event.
+    pub at: i64,
+    /// Pause-adjusted elapsed time of the run at the event, in milliseconds.
+    pub offset_ms: i64,
+    #[serde(flatten)]
+    pub kind: RunEventKind,
+}
+
+/// A run's timeline, returned by `get_run_timeline`.
+#[derive(Serialize, Clone, Debug)]
+pub struct RunTimeline {
+    pub run_id: String,
+    /// `false` if the run was saved without a timeline and `events` only
+    /// holds its start and finish, derived from the run's timestamp and
+    /// duration.
+    pub recorded: bool,
+    /// Total time spent paused, in milliseconds.
+    pub paused_ms: i64,
+    /// Events in order.
+    pub events: Vec<RunEvent>,
+}
+
+impl RunTimeline {
+    /// Builds the timeline of a saved run.
+    pub fn for_run(run: &RunRecord) -> Self {
+        if run.timeline.is_empty() {
+            let event = |at, offset_ms, kind| RunEvent {
+                at,
+                offset_ms,
+                kind,
+            };
+            return RunTimeline {
+                run_id: run.id.clone(),
+                recorded: false,
+                paused_ms: 0,
+                events: vec![
+                    event(run.timestamp, 0, RunEventKind::Start),
+                    event(
+                        run.timestamp + run.duration_ms,
+                        run.duration_ms,
+                        RunEventKind::Finish,
+                    ),
+                ],
+            };
+        }
+
+        let mut paused_ms = 0;
+        let mut paused_since = None;
+        for event in &run.timeline {
+            match event.kind {
+                RunEventKind::Pause => paused_since = paused_since.or(Some(event.at)),
+                RunEventKind::Resume | RunEventKind::Finish => {
+                    if let Some(since) = paused_since.take() {
+                        paused_ms += (event.at - since).max(0);
+                    }
+                }
+                _ => {}
+            }
+        }
+        RunTimeline {
+            run_id: run.id.clone(),
+            recorded: true,
+            paused_ms,
+            events: run.timeline.clone(),
+        }
+    }
+}
+
+/// Snapshot of the run in progress, written to disk so it survives a crash.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RunCheckpoint {
+    /// Schema version of the checkpoint, see
+    /// [`CHECKPOINT_SCHEMA_VERSION`](crate::migrations::CHECKPOINT_SCHEMA_VERSION).
+    #[serde(rename = "schemaVersion", default)]
+    pub schema_version: u32,
+    /// ID the run is saved under once finished. Missing in checkpoints
+    /// written by older versions.
+    #[serde(default)]
+    pub run_id: Option<String>,
+    /// Scene identifier of the run.
+    pub scene_id: String,
+    /// Whether this is a Terror Zone run.
+    pub is_tz: bool,
+    /// The session the run belongs to, if any.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// Unix timestamp (milliseconds) when the run started.
+    pub started_at: i64,
+    /// Pauses so far, oldest first.
+    #[serde(default)]
+    pub pauses: Vec<PauseInterval>,
+    /// Drops recorded so far.
+    #[serde(default)]
+    pub drops: Vec<String>,
+    /// Elapsed time in milliseconds at each split so far.
+    #[serde(default)]
+    pub splits: Vec<i64>,
+    /// Events recorded so far.
+    #[serde(default)]
+    pub timeline: Vec<RunEvent>,
+    /// Pause-adjusted elapsed time when the checkpoint was taken.
+    pub elapsed_ms: i64,
+    /// Unix timestamp (milliseconds) when the checkpoint was taken.
+    pub saved_at: i64,
+}
+
+// ============================================================================
+// Sessions
+// ============================================================================
+
+/// A farming session: consecutive runs of one scene.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Session {
+    /// Unique identifier for this session.
+    pub id: String,
+    /// Scene identifier the session was started in.
+    pub scene_id: String,
+    /// Unix timestamp (milliseconds) when the session started.
+    pub started_at: i64,
+    /// Unix timestamp (milliseconds) when the session ended; `None` while open.
+    pub ended_at: Option<i64>,
+    /// Whether the session was started as a Terror Zone session.
+    pub is_tz: bool,
+    /// Free-form notes.
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// A session with aggregate statistics over its runs.
+#[derive(Serialize, Debug)]
+pub struct SessionSummary {
+    /// The session.
+    #[serde(flatten)]
+    pub session: Session,
+    /// Duration statistics of the session's runs.
+    pub stats: DurationStats,
+    /// Number of drops recorded in the session.
+    pub drops: usize,
+}
+
+impl SessionSummary {
+    /// Summarizes a session from its runs.
+    pub fn new(session: Session, runs: &[&RunRecord]) -> Self {
+        Self {
+            session,
+            stats: DurationStats::from_runs(runs.iter().copied()),
+            drops: runs.iter().map(|r| r.drops.len()).sum(),
+        }
+    }
+
+    /// Summarizes several sessions in one pass over the runs.
+    pub fn for_sessions(sessions: Vec<Session>, runs: &[&RunRecord]) -> Vec<Self> {
+        let mut by_session: HashMap<&str, Vec<&RunRecord>> = HashMap::new();
+        for &run in runs {
+            if let Some(ref id) = run.session_id {
+                by_session.entry(id.as_str()).or_default().push(run);
+            }
+        }
+        sessions
+            .into_iter()
+            .map(|session| {
+                let runs = by_session
+                    .get(session.id.as_str())
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                Self::new(session, runs)
+            })
+            .collect()
+    }
+}
+
+/// A session with its summary and runs, returned by `get_session`.
+#[derive(Serialize, Debug)]
+pub struct SessionDetail {
+    /// The session and its statistics.
+    #[serde(flatten)]
+    pub summary: SessionSummary,
+    /// The session's runs, oldest first.
+    pub runs: Vec<RunRecord>,
 }
 
 /// Filter parameters for querying run history.
 ///
 /// Uses camelCase field names to match frontend JavaScript conventions.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Default)]
 #[allow(non_snake_case)]
 pub struct HistoryFilter {
     /// Start date filter (inclusive, YYYY-MM-DD format).
@@ -184,4 +637,219 @@ pub struct HistoryFilter {
     pub endStr: Option<String>,
     /// Scene ID filter ("all" for no filter).
     pub sceneId: Option<String>,
+    /// Multiple scene IDs; a run matches if its scene is any of them.
+    pub sceneIds: Option<Vec<String>>,
+    /// Start time filter (inclusive, Unix milliseconds).
+    pub startTime: Option<i64>,
+    /// End time filter (exclusive, Unix milliseconds).
+    pub endTime: Option<i64>,
+    /// Terror Zone filter.
+    pub isTz: Option<bool>,
+    /// Minimum duration in milliseconds (inclusive).
+    pub minDurationMs: Option<i64>,
+    /// Maximum duration in milliseconds (inclusive).
+    pub maxDurationMs: Option<i64>,
+    /// Whether the run has at least one drop.
+    pub hasDrops: Option<bool>,
+    /// Only runs that dropped this item ID.
+    pub itemId: Option<String>,
+    /// Only runs with at least one drop of this rarity class.
+    pub rarity: Option<RarityClass>,
+    /// Only runs recorded in this session.
+    pub sessionId: Option<String>,
+}
+
+impl HistoryFilter {
+    /// Returns whether a run matches every set criterion of this filter.
+    ///
+    /// # Arguments
+    /// * `run` - The run to check.
+    /// * `qualities` - Custom item qualities, for the rarity criterion.
+    pub fn matches(&self, run: &RunRecord, qualities: &Qualities) -> bool {
+        // Filter by scene ID
+        if let Some(ref sid) = self.sceneId {
+            if sid != "all" && &run.scene_id != sid {
+                return false;
+            }
+        }
+        if let Some(ref ids) = self.sceneIds {
+            if !ids.is_empty() && !ids.contains(&run.scene_id) {
+                return false;
+            }
+        }
+        // Filter by start date
+        if let Some(ref s) = self.startStr {
+            if &run.date_str < s {
+                return false;
+            }
+        }
+        // Filter by end date
+        if let Some(ref e) = self.endStr {
+            if &run.date_str > e {
+                return false;
+            }
+        }
+        // Filter by exact time range
+        if self.startTime.is_some_and(|t| run.timestamp < t) {
+            return false;
+        }
+        if self.endTime.is_some_and(|t| run.timestamp >= t) {
+            return false;
+        }
+        if self.isTz.is_some_and(|tz| run.is_tz != tz) {
+            return false;
+        }
+        // Filter by duration
+        if self.minDurationMs.is_some_and(|d| run.duration_ms < d) {
+            return false;
+        }
+        if self.maxDurationMs.is_some_and(|d| run.duration_ms > d) {
+            return false;
+        }
+        // Filter by drops
+        if self.hasDrops.is_some_and(|has| run.drops.is_empty() == has) {
+            return false;
+        }
+        if let Some(ref item) = self.itemId {
+            if !run.drops.contains(item) {
+                return false;
+            }
+        }
+        if let Some(ref session) = self.sessionId {
+            if run.session_id.as_ref() != Some(session) {
+                return false;
+            }
+        }
+        if let Some(rarity) = self.rarity {
+            if !run
+                .drops
+                .iter()
+                .any(|d| items::rarity_of(d, qualities) == Some(rarity))
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Field used to order a page of runs.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RunSortKey {
+    /// Start time of the run.
+    #[default]
+    Timestamp,
+    /// Run duration.
+    Duration,
+    /// Scene identifier.
+    Scene,
+    /// Number of drops.
+    Drops,
+}
+
+/// Sort direction for run queries.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// Sorting and paging parameters for run queries.
+///
+/// Uses camelCase field names to match frontend JavaScript conventions.
+/// Defaults to newest first with no limit.
+#[derive(Deserialize, Debug, Default)]
+#[allow(non_snake_case)]
+pub struct RunQuery {
+    /// Sort key (default: timestamp).
+    pub sortBy: Option<RunSortKey>,
+    /// Sort direction (default: descending).
+    pub sortDir: Option<SortDirection>,
+    /// Number of matching runs to skip.
+    pub offset: Option<usize>,
+    /// Maximum number of runs to return (all if unset).
+    pub limit: Option<usize>,
+}
+
+/// One page of a run query.
+#[derive(Serialize, Debug)]
+pub struct RunPage {
+    /// Runs on this page, in the requested order.
+    pub runs: Vec<RunRecord>,
+    /// Number of runs matching the filter across all pages.
+    pub total: usize,
+}
+
+/// Builds a run record for tests.
+#[cfg(test)]
+pub(crate) fn test_run(
+    scene: &str,
+    date: &str,
+    duration_ms: i64,
+    drops: &[&str],
+    is_tz: bool,
+) -> RunRecord {
+    RunRecord {
+        id: format!("{}-{}-{}", scene, date, duration_ms),
+        timestamp: 0,
+        date_str: date.to_string(),
+        scene_id: scene.to_string(),
+        duration_ms,
+        drops: drops.iter().map(|d| d.to_string()).collect(),
+        is_tz,
+        edits: Vec::new(),
+        session_id: None,
+        timeline: Vec::new(),
+        segments: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(at: i64, kind: RunEventKind) -> RunEvent {
+        RunEvent {
+            at,
+            offset_ms: 0,
+            kind,
+        }
+    }
+
+    #[test]
+    fn timeline_without_events_is_derived_from_the_run() {
+        let mut run = test_run("pit", "2024-06-01", 60_000, &[], false);
+        run.timestamp = 1_000;
+
+        let timeline = RunTimeline::for_run(&run);
+        assert!(!timeline.recorded);
+        assert_eq!(timeline.paused_ms, 0);
+        assert_eq!(timeline.events.len(), 2);
+        assert_eq!(timeline.events[1].at, 61_000);
+        assert_eq!(timeline.events[1].offset_ms, 60_000);
+    }
+
+    #[test]
+    fn paused_time_counts_until_resume_or_finish() {
+        let mut run = test_run("pit", "2024-06-01", 60_000, &[], false);
+        run.timeline = vec![
+            event(0, RunEventKind::Start),
+            event(1_000, RunEventKind::Pause),
+            // A repeated pause does not restart the interval
+            event(2_000, RunEventKind::Pause),
+            event(3_000, RunEventKind::Resume),
+            event(4_000, RunEventKind::Split),
+            // Still paused when the run finished
+            event(5_000, RunEventKind::Pause),
+            event(9_000, RunEventKind::Finish),
+        ];
+
+        let timeline = RunTimeline::for_run(&run);
+        assert!(timeline.recorded);
+        assert_eq!(timeline.paused_ms, 6_000);
+        assert_eq!(timeline.events, run.timeline);
+    }
 }
@@ -0,0 +1,255 @@
+//! Scene registry.
+//!
+//! Built-in scenes are stored in `data/scenes.json` and embedded at compile
+//! time; custom scenes live in the `scenes` table of the run database. Every
+//! scene has a stable ID, which is what `RunRecord.scene_id` stores, so
+//! renaming a scene never orphans its history.
+//!
+//! Runs recorded before the registry existed used the Chinese scene name as
+//! `scene_id`; [`canonical_id`] maps those names to built-in IDs.
+
+use crate::db::Database;
+use crate::error::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Raw built-in scene data, embedded at compile time.
+const SCENES_JSON: &str = include_str!("../data/scenes.json");
+
+/// Prefix of custom scene IDs.
+pub const CUSTOM_SCENE_PREFIX: &str = "custom-";
+
+/// Maximum length of a scene name, label or segment name, in characters.
+const MAX_NAME_LEN: usize = 40;
+/// Maximum number of segments per scene.
+const MAX_SEGMENTS: usize = 20;
+
+/// The user-editable part of a scene.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SceneInfo {
+    /// Chinese scene name.
+    #[serde(default)]
+    pub name_zh: String,
+    /// English scene name.
+    #[serde(default)]
+    pub name_en: String,
+    /// Short Chinese label shown on the scene card.
+    #[serde(default)]
+    pub label_zh: String,
+    /// Short English label shown on the scene card.
+    #[serde(default)]
+    pub label_en: String,
+    /// Act the scene is in (1-5), if it belongs to one.
+    #[serde(default)]
+    pub act: Option<u8>,
+    /// In-game area, e.g. "Chaos Sanctuary".
+    #[serde(default)]
+    pub area: Option<String>,
+    /// Segment names in split order, see [`crate::segments`].
+    #[serde(default)]
+    pub segments: Vec<String>,
+}
+
+impl SceneInfo {
+    /// Trims the fields, fills missing names and labels from the other
+    /// language and checks the limits.
+    ///
+    /// # Returns
+    /// The cleaned-up info, or [`AppError::Validation`] describing the
+    /// first invalid field.
+    pub fn normalized(mut self) -> AppResult<Self> {
+        for field in [
+            &mut self.name_zh,
+            &mut self.name_en,
+            &mut self.label_zh,
+            &mut self.label_en,
+        ] {
+            *field = field.trim().to_string();
+        }
+        if self.name_zh.is_empty() && self.name_en.is_empty() {
+            return Err(AppError::Validation("scene name is required".to_string()));
+        }
+        if self.name_zh.is_empty() {
+            self.name_zh = self.name_en.clone();
+        }
+        if self.name_en.is_empty() {
+            self.name_en = self.name_zh.clone();
+        }
+        if self.label_zh.is_empty() {
+            self.label_zh = self.name_zh.clone();
+        }
+        if self.label_en.is_empty() {
+            self.label_en = self.name_en.clone();
+        }
+
+        if let Some(act) = self.act {
+            if !(1..=5).contains(&act) {
+                return Err(AppError::Validation(format!("act {} does not exist", act)));
+            }
+        }
+        self.area = self
+            .area
+            .map(|a| a.trim().to_string())
+            .filter(|a| !a.is_empty());
+
+        self.segments = self.segments.iter().map(|s| s.trim().to_string()).collect();
+        if self.segments.len() > MAX_SEGMENTS {
+            return Err(AppError::Validation(format!(
+                "at most {} segments are allowed",
+                MAX_SEGMENTS
+            )));
+        }
+        if self.segments.iter().any(String::is_empty) {
+            return Err(AppError::Validation("segment name is empty".to_string()));
+        }
+
+        let names = [&self.name_zh, &self.name_en, &self.label_zh, &self.label_en];
+        let too_long = names
+            .into_iter()
+            .chain(self.area.as_ref())
+            .chain(&self.segments)
+            .find(|s| s.chars().count() > MAX_NAME_LEN);
+        if let Some(name) = too_long {
+            return Err(AppError::Validation(format!(
+                "'{}' is longer than {} characters",
+                name, MAX_NAME_LEN
+            )));
+        }
+        Ok(self)
+    }
+}
+
+/// A scene of the registry.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Scene {
+    /// Stable identifier, as stored in `RunRecord.scene_id`.
+    pub id: String,
+    /// Whether the scene was created by the user.
+    #[serde(default)]
+    pub custom: bool,
+    #[serde(flatten)]
+    pub info: SceneInfo,
+}
+
+/// Returns the built-in scenes, in display order.
+pub fn builtin() -> &'static [Scene] {
+    static BUILTIN: OnceLock<Vec<Scene>> = OnceLock::new();
+    BUILTIN
+        .get_or_init(|| serde_json::from_str(SCENES_JSON).expect("embedded scenes.json is valid"))
+}
+
+/// Returns whether an ID belongs to a built-in scene.
+pub fn is_builtin(id: &str) -> bool {
+    builtin().iter().any(|s| s.id == id)
+}
+
+/// Maps a legacy scene name to its built-in scene ID.
+///
+/// # Returns
+/// The built-in ID if `scene_id` is the Chinese name of a built-in scene,
+/// otherwise `scene_id` unchanged.
+pub fn canonical_id(scene_id: &str) -> String {
+    builtin()
+        .iter()
+        .find(|s| s.info.name_zh == scene_id)
+        .map_or(scene_id, |s| s.id.as_str())
+        .to_string()
+}
+
+/// Maps a scene ID to its canonical form and checks that the scene exists.
+///
+/// Used for scene IDs written into existing runs and sessions, which unlike
+/// new runs may not name a scene that has been deleted or never existed.
+///
+/// # Returns
+/// The canonical ID, or [`AppError::Validation`] if no built-in or custom
+/// scene has it.
+pub fn resolve(db: &Database, scene_id: &str) -> AppResult<String> {
+    let id = canonical_id(scene_id);
+    if find(db, &id)?.is_none() {
+        return Err(AppError::Validation(format!("unknown scene: {}", scene_id)));
+    }
+    Ok(id)
+}
+
+/// Returns all scenes: built-in ones first, then custom ones by name.
+pub fn all(db: &Database) -> AppResult<Vec<Scene>> {
+    let mut scenes = builtin().to_vec();
+    scenes.extend(db.get_scenes()?);
+    Ok(scenes)
+}
+
+/// Looks up a built-in or custom scene by ID.
+pub fn find(db: &Database, id: &str) -> AppResult<Option<Scene>> {
+    match builtin().iter().find(|s| s.id == id) {
+        Some(scene) => Ok(Some(scene.clone())),
+        None => Ok(db.get_scene(id)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(name_en: &str) -> SceneInfo {
+        SceneInfo {
+            name_zh: String::new(),
+            name_en: name_en.to_string(),
+            label_zh: String::new(),
+            label_en: String::new(),
+            act: None,
+            area: None,
+            segments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn builtin_scenes_have_unique_ids_and_map_legacy_names() {
+        let scenes = builtin();
+        assert_eq!(scenes.len(), 12);
+        let mut ids: Vec<&str> = scenes.iter().map(|s| s.id.as_str()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), scenes.len());
+        assert!(scenes.iter().all(|s| s.info.clone().normalized().is_ok()));
+
+        assert_eq!(canonical_id("混沌避难所"), "chaos");
+        assert_eq!(canonical_id("chaos"), "chaos");
+        assert_eq!(canonical_id("custom-1"), "custom-1");
+    }
+
+    #[test]
+    fn resolve_canonicalizes_and_rejects_unknown_scenes() {
+        let db = Database::open_in_memory().unwrap();
+        assert_eq!(resolve(&db, "混沌避难所").unwrap(), "chaos");
+        assert_eq!(resolve(&db, "chaos").unwrap(), "chaos");
+        assert!(matches!(
+            resolve(&db, "custom-1"),
+            Err(AppError::Validation(_))
+        ));
+
+        let scene = Scene {
+            id: "custom-1".to_string(),
+            custom: true,
+            info: info("Arcane"),
+        };
+        db.write_scene(&scene).unwrap();
+        assert_eq!(resolve(&db, "custom-1").unwrap(), "custom-1");
+    }
+
+    #[test]
+    fn scene_info_is_normalized_and_validated() {
+        let scene = info("  Arcane Sanctuary ").normalized().unwrap();
+        assert_eq!(scene.name_en, "Arcane Sanctuary");
+        assert_eq!(scene.name_zh, "Arcane Sanctuary");
+        assert_eq!(scene.label_en, "Arcane Sanctuary");
+
+        assert!(info(" ").normalized().is_err());
+        let mut bad_act = info("Arcane");
+        bad_act.act = Some(6);
+        assert!(bad_act.normalized().is_err());
+        let mut empty_segment = info("Arcane");
+        empty_segment.segments = vec!["Summoner".to_string(), " ".to_string()];
+        assert!(empty_segment.normalized().is_err());
+    }
+}
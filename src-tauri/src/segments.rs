@@ -0,0 +1,118 @@
+//! Named segments within a run.
+//!
+//! Long runs such as Chaos Sanctuary or Baal are split into segments (seals,
+//! Diablo, waves) by pressing the split hotkey. Each scene has an ordered list
+//! of segment names, defined in the scene registry (see [`crate::scenes`]),
+//! which the user can override per scene in the config (`sceneSegments`).
+//!
+//! A run with `n` splits has `n + 1` segments; the last one ends when the run
+//! is finished.
+
+use crate::models::{AppConfig, RunEventKind, RunRecord, SegmentTime};
+
+/// Returns the segment names of a scene.
+///
+/// # Arguments
+/// * `config` - The app config, whose `sceneSegments` take precedence.
+/// * `scene_id` - The scene identifier.
+/// * `defined` - The segments defined on the scene in the registry.
+///
+/// # Returns
+/// The names in order, or an empty list if the scene has no segments.
+pub fn names_for(config: &AppConfig, scene_id: &str, defined: &[String]) -> Vec<String> {
+    config
+        .scene_segments
+        .as_ref()
+        .and_then(|s| s.get(scene_id))
+        .map_or_else(|| defined.to_vec(), Clone::clone)
+}
+
+/// Splits a finished run into named segments.
+///
+/// Segments beyond the defined names are called "Segment N". A run without
+/// splits has no segments.
+///
+/// # Arguments
+/// * `names` - Segment names of the run's scene.
+/// * `splits` - Elapsed time in milliseconds at each split, in order.
+/// * `duration_ms` - Total duration of the run.
+pub fn segment_times(names: &[String], splits: &[i64], duration_ms: i64) -> Vec<SegmentTime> {
+    if splits.is_empty() {
+        return Vec::new();
+    }
+
+    let mut start = 0;
+    splits
+        .iter()
+        .copied()
+        .chain(std::iter::once(duration_ms))
+        .enumerate()
+        .map(|(i, end)| {
+            let segment = SegmentTime {
+                name: names
+                    .get(i)
+                    .cloned()
+                    .unwrap_or_else(|| format!("Segment {}", i + 1)),
+                duration_ms: (end - start).max(0),
+            };
+            start = end.max(start);
+            segment
+        })
+        .collect()
+}
+
+/// Splits a finished run into named segments at the split events of its
+/// timeline.
+///
+/// # Arguments
+/// * `names` - Segment names of the run's scene.
+/// * `run` - The finished run.
+pub fn for_run(names: &[String], run: &RunRecord) -> Vec<SegmentTime> {
+    let splits: Vec<i64> = run
+        .timeline
+        .iter()
+        .filter(|e| e.kind == RunEventKind::Split)
+        .map(|e| e.offset_ms)
+        .collect();
+    segment_times(names, &splits, run.duration_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_become_named_segments() {
+        let names = vec!["A".to_string(), "B".to_string()];
+        let segments = segment_times(&names, &[1000, 2500, 4000], 5000);
+        let got: Vec<(&str, i64)> = segments
+            .iter()
+            .map(|s| (s.name.as_str(), s.duration_ms))
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                ("A", 1000),
+                ("B", 1500),
+                ("Segment 3", 1500),
+                ("Segment 4", 1000)
+            ]
+        );
+        assert!(segment_times(&names, &[], 5000).is_empty());
+    }
+
+    #[test]
+    fn config_overrides_scene_segments() {
+        let mut config = AppConfig::default();
+        let defined = vec!["Vizier Seal".to_string(), "Diablo".to_string()];
+        assert_eq!(names_for(&config, "chaos", &defined), defined);
+        assert!(names_for(&config, "cows", &[]).is_empty());
+
+        config.scene_segments = Some(
+            [("chaos".to_string(), vec!["Seals".to_string()])]
+                .into_iter()
+                .collect(),
+        );
+        assert_eq!(names_for(&config, "chaos", &defined), vec!["Seals"]);
+    }
+}
@@ -0,0 +1,348 @@
+//! Aggregate statistics over run records.
+//!
+//! Statistics are computed in one pass over a filtered run list, so the
+//! History view and dashboard show the same numbers.
+
+use crate::custom_items::Qualities;
+use crate::items::{self, RarityClass};
+use crate::models::RunRecord;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Duration statistics for a group of runs.
+///
+/// All durations are in milliseconds; order statistics are `None` for an
+/// empty group.
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct DurationStats {
+    /// Number of runs.
+    pub count: usize,
+    /// Sum of all durations.
+    pub total_ms: i64,
+    /// Mean duration, rounded down.
+    pub mean_ms: i64,
+    /// Median duration (mean of the two middle runs for even counts).
+    pub median_ms: Option<i64>,
+    /// 90th percentile duration (nearest rank).
+    pub p90_ms: Option<i64>,
+    /// Fastest run.
+    pub best_ms: Option<i64>,
+    /// Slowest run.
+    pub worst_ms: Option<i64>,
+}
+
+impl DurationStats {
+    /// Computes duration statistics for a set of runs.
+    pub fn from_runs<'a>(runs: impl IntoIterator<Item = &'a RunRecord>) -> Self {
+        Self::from_durations(runs.into_iter().map(|r| r.duration_ms).collect())
+    }
+
+    /// Computes statistics for a list of durations in milliseconds.
+    pub fn from_durations(mut durations: Vec<i64>) -> Self {
+        if durations.is_empty() {
+            return Self::default();
+        }
+        durations.sort_unstable();
+
+        let count = durations.len();
+        let total_ms: i64 = durations.iter().sum();
+        let mid = count / 2;
+        let median_ms = match count % 2 {
+            0 => (durations[mid - 1] + durations[mid]) / 2,
+            _ => durations[mid],
+        };
+        // Nearest rank: the smallest value with at least 90% of runs at or below it
+        let p90_rank = (count * 9).div_ceil(10);
+
+        Self {
+            count,
+            total_ms,
+            mean_ms: total_ms / count as i64,
+            median_ms: Some(median_ms),
+            p90_ms: Some(durations[p90_rank - 1]),
+            best_ms: durations.first().copied(),
+            worst_ms: durations.last().copied(),
+        }
+    }
+}
+
+/// Statistics for a single scene.
+#[derive(Serialize, Debug)]
+pub struct SceneStats {
+    /// Scene identifier.
+    pub scene_id: String,
+    /// Duration statistics of the scene's runs.
+    #[serde(flatten)]
+    pub durations: DurationStats,
+    /// Number of drops recorded in the scene.
+    pub drops: usize,
+}
+
+/// Aggregate statistics returned by `get_stats`.
+#[derive(Serialize, Debug)]
+pub struct RunStats {
+    /// Duration statistics over all matching runs.
+    pub overall: DurationStats,
+    /// Per-scene statistics, most-run scene first.
+    pub scenes: Vec<SceneStats>,
+    /// Terror Zone runs.
+    pub tz: DurationStats,
+    /// Non-Terror Zone runs.
+    pub non_tz: DurationStats,
+    /// Total number of drops.
+    pub total_drops: usize,
+    /// Number of distinct items dropped, for collection progress.
+    pub distinct_drops: usize,
+    /// Drop counts by rarity class; drops of unknown items are not counted here.
+    pub drops_by_rarity: BTreeMap<RarityClass, usize>,
+    /// Number of distinct days with at least one run.
+    pub active_days: usize,
+    /// Average runs per active day.
+    pub runs_per_day: f64,
+}
+
+impl RunStats {
+    /// Computes aggregate statistics for a set of runs.
+    ///
+    /// # Arguments
+    /// * `runs` - The runs to aggregate.
+    /// * `qualities` - Custom item qualities, for the rarity counts.
+    pub fn from_runs(runs: &[&RunRecord], qualities: &Qualities) -> Self {
+        let mut by_scene: HashMap<&str, Vec<&RunRecord>> = HashMap::new();
+        let mut drops_by_rarity = BTreeMap::new();
+        let mut days = HashSet::new();
+        let mut distinct = HashSet::new();
+
+        for &run in runs {
+            by_scene.entry(run.scene_id.as_str()).or_default().push(run);
+            days.insert(run.date_str.as_str());
+            distinct.extend(run.drops.iter().map(String::as_str));
+            for rarity in run
+                .drops
+                .iter()
+                .filter_map(|d| items::rarity_of(d, qualities))
+            {
+                *drops_by_rarity.entry(rarity).or_insert(0) += 1;
+            }
+        }
+
+        let mut scenes: Vec<SceneStats> = by_scene
+            .into_iter()
+            .map(|(scene_id, runs)| SceneStats {
+                scene_id: scene_id.to_string(),
+                drops: runs.iter().map(|r| r.drops.len()).sum(),
+                durations: DurationStats::from_runs(runs),
+            })
+            .collect();
+        scenes.sort_by(|a, b| {
+            b.durations
+                .count
+                .cmp(&a.durations.count)
+                .then_with(|| a.scene_id.cmp(&b.scene_id))
+        });
+
+        let active_days = days.len();
+        Self {
+            overall: DurationStats::from_runs(runs.iter().copied()),
+            scenes,
+            tz: DurationStats::from_runs(runs.iter().copied().filter(|r| r.is_tz)),
+            non_tz: DurationStats::from_runs(runs.iter().copied().filter(|r| !r.is_tz)),
+            total_drops: runs.iter().map(|r| r.drops.len()).sum(),
+            distinct_drops: distinct.len(),
+            drops_by_rarity,
+            active_days,
+            runs_per_day: if active_days == 0 {
+                0.0
+            } else {
+                runs.len() as f64 / active_days as f64
+            },
+        }
+    }
+}
+
+/// Statistics for one named segment.
+#[derive(Serialize, Debug)]
+pub struct SegmentStats {
+    /// Segment name.
+    pub name: String,
+    /// Duration statistics of the segment over the runs that completed it.
+    #[serde(flatten)]
+    pub durations: DurationStats,
+}
+
+/// Per-segment statistics of one scene, returned by `get_segment_stats`.
+#[derive(Serialize, Debug)]
+pub struct SegmentReport {
+    /// Scene identifier.
+    pub scene_id: String,
+    /// Number of runs that were split into segments.
+    pub runs: usize,
+    /// Segments in order: the scene's defined segments first, then any other
+    /// segment names found in its runs.
+    pub segments: Vec<SegmentStats>,
+    /// Sum of the best time of every segment; `None` until each segment has
+    /// been completed at least once.
+    pub sum_of_best_ms: Option<i64>,
+    /// Fastest run that was split into segments.
+    pub best_run_ms: Option<i64>,
+}
+
+impl SegmentReport {
+    /// Computes segment statistics for the runs of one scene.
+    ///
+    /// # Arguments
+    /// * `scene_id` - The scene; runs of other scenes are ignored.
+    /// * `names` - The scene's defined segment names.
+    /// * `runs` - The runs to analyze.
+    pub fn from_runs(scene_id: &str, names: &[String], runs: &[&RunRecord]) -> Self {
+        let split_runs: Vec<&RunRecord> = runs
+            .iter()
+            .copied()
+            .filter(|r| r.scene_id == scene_id && !r.segments.is_empty())
+            .collect();
+
+        let mut order: Vec<&str> = names.iter().map(String::as_str).collect();
+        let mut durations: HashMap<&str, Vec<i64>> = HashMap::new();
+        for segment in split_runs.iter().flat_map(|r| &r.segments) {
+            if !order.contains(&segment.name.as_str()) {
+                order.push(&segment.name);
+            }
+            durations
+                .entry(&segment.name)
+                .or_default()
+                .push(segment.duration_ms);
+        }
+
+        let segments: Vec<SegmentStats> = order
+            .into_iter()
+            .map(|name| SegmentStats {
+                name: name.to_string(),
+                durations: DurationStats::from_durations(
+                    durations.remove(name).unwrap_or_default(),
+                ),
+            })
+            .collect();
+        let sum_of_best_ms = if segments.is_empty() {
+            None
+        } else {
+            segments.iter().map(|s| s.durations.best_ms).sum()
+        };
+
+        Self {
+            scene_id: scene_id.to_string(),
+            runs: split_runs.len(),
+            best_run_ms: split_runs.iter().map(|r| r.duration_ms).min(),
+            segments,
+            sum_of_best_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_items::CustomQuality;
+    use crate::models::{test_run as run, SegmentTime};
+
+    #[test]
+    fn empty_group_has_no_order_statistics() {
+        let stats = RunStats::from_runs(&[], &Qualities::new());
+        assert_eq!(stats.overall, DurationStats::default());
+        assert!(stats.scenes.is_empty());
+        assert_eq!(stats.runs_per_day, 0.0);
+    }
+
+    #[test]
+    fn duration_stats_use_median_and_nearest_rank_p90() {
+        let runs: Vec<RunRecord> = (1..=10)
+            .map(|i| run("pit", "2024-06-01", i * 1000, &[], false))
+            .collect();
+        let stats = DurationStats::from_runs(&runs);
+        assert_eq!(stats.count, 10);
+        assert_eq!(stats.total_ms, 55_000);
+        assert_eq!(stats.mean_ms, 5_500);
+        assert_eq!(stats.median_ms, Some(5_500));
+        assert_eq!(stats.p90_ms, Some(9_000));
+        assert_eq!(stats.best_ms, Some(1_000));
+        assert_eq!(stats.worst_ms, Some(10_000));
+
+        let odd = DurationStats::from_runs(&runs[..3]);
+        assert_eq!(odd.median_ms, Some(2_000));
+        assert_eq!(odd.p90_ms, Some(3_000));
+    }
+
+    #[test]
+    fn groups_by_scene_tz_and_rarity() {
+        let runs = [
+            run(
+                "pit",
+                "2024-06-01",
+                60_000,
+                &["r30", "custom:Jewel:2"],
+                true,
+            ),
+            run("pit", "2024-06-01", 80_000, &["custom-1"], false),
+            run("countess", "2024-06-02", 40_000, &["r30", "unknown"], false),
+        ];
+        let refs: Vec<&RunRecord> = runs.iter().collect();
+        let qualities = Qualities::from([("custom-1".to_string(), CustomQuality::Rare)]);
+        let stats = RunStats::from_runs(&refs, &qualities);
+
+        assert_eq!(stats.scenes[0].scene_id, "pit");
+        assert_eq!(stats.scenes[0].durations.count, 2);
+        assert_eq!(stats.scenes[0].durations.median_ms, Some(70_000));
+        assert_eq!(stats.scenes[0].drops, 3);
+        assert_eq!(stats.scenes[1].scene_id, "countess");
+
+        assert_eq!(stats.tz.count, 1);
+        assert_eq!(stats.non_tz.count, 2);
+        assert_eq!(stats.non_tz.best_ms, Some(40_000));
+
+        assert_eq!(stats.total_drops, 5);
+        assert_eq!(stats.distinct_drops, 4);
+        assert_eq!(stats.drops_by_rarity.get(&RarityClass::Rune), Some(&2));
+        assert_eq!(stats.drops_by_rarity.get(&RarityClass::Magic), Some(&1));
+        assert_eq!(stats.drops_by_rarity.get(&RarityClass::Rare), Some(&1));
+        // Custom items missing from the index are not classified
+        let stats = RunStats::from_runs(&refs, &Qualities::new());
+        assert_eq!(stats.drops_by_rarity.get(&RarityClass::Rare), None);
+        assert_eq!(stats.active_days, 2);
+        assert_eq!(stats.runs_per_day, 1.5);
+    }
+
+    #[test]
+    fn segment_report_sums_best_segments() {
+        let split = |duration_ms, segments: &[(&str, i64)]| {
+            let mut r = run("chaos", "2024-06-01", duration_ms, &[], false);
+            r.segments = segments
+                .iter()
+                .map(|&(name, duration_ms)| SegmentTime {
+                    name: name.to_string(),
+                    duration_ms,
+                })
+                .collect();
+            r
+        };
+        let runs = [
+            split(300, &[("Seals", 200), ("Diablo", 100)]),
+            split(280, &[("Seals", 150), ("Diablo", 130)]),
+            split(90, &[("Seals", 90)]),
+            run("chaos", "2024-06-01", 100, &[], false),
+        ];
+        let refs: Vec<&RunRecord> = runs.iter().collect();
+        let names = vec!["Seals".to_string(), "Diablo".to_string()];
+
+        let report = SegmentReport::from_runs("chaos", &names, &refs);
+        assert_eq!(report.runs, 3);
+        assert_eq!(report.segments[0].durations.count, 3);
+        assert_eq!(report.segments[0].durations.best_ms, Some(90));
+        assert_eq!(report.segments[1].durations.mean_ms, 115);
+        assert_eq!(report.sum_of_best_ms, Some(190));
+        assert_eq!(report.best_run_ms, Some(90));
+
+        let waves = vec!["Wave 1".to_string()];
+        let report = SegmentReport::from_runs("chaos", &waves, &refs);
+        assert_eq!(report.segments.len(), 3);
+        assert_eq!(report.sum_of_best_ms, None);
+    }
+}
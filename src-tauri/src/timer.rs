@@ -0,0 +1,584 @@
+//! Authoritative run timer.
+//!
+//! The timer state machine (idle → running ⇄ paused → idle) lives in the
+//! backend and measures elapsed time on the monotonic clock, so it neither
+//! drifts when the webview is throttled nor jumps when the wall clock changes.
+//! Wall-clock timestamps are only recorded for display (run start, pause
+//! intervals, timeline events).
+//!
+//! The frontend mirrors the timer through two events: `timer-state` after every
+//! transition and `timer-tick` while a run is running.
+
+use crate::checkpoint;
+use crate::error::{AppError, AppResult};
+use crate::migrations::CHECKPOINT_SCHEMA_VERSION;
+use crate::models::{PauseInterval, RunCheckpoint, RunEvent, RunEventKind, RunRecord};
+use crate::utils::AppState;
+use serde::Serialize;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Event emitted with a [`TimerSnapshot`] after every state transition.
+pub const TIMER_STATE_EVENT: &str = "timer-state";
+/// Event emitted with a [`TimerTick`] while a run is running.
+pub const TIMER_TICK_EVENT: &str = "timer-tick";
+/// Event emitted with an error message when the run could not be checkpointed.
+pub const CHECKPOINT_ERROR_EVENT: &str = "checkpoint-error";
+/// Interval between tick events.
+const TICK_INTERVAL: Duration = Duration::from_millis(50);
+/// Interval between checkpoints of a running run.
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Phase of the run timer.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimerPhase {
+    /// No run in progress.
+    Idle,
+    /// A run is being timed.
+    Running,
+    /// A run is in progress but paused.
+    Paused,
+}
+
+/// Full timer state, sent with [`TIMER_STATE_EVENT`].
+#[derive(Serialize, Clone, Debug)]
+pub struct TimerSnapshot {
+    /// Current phase.
+    pub phase: TimerPhase,
+    /// Scene of the run in progress.
+    pub scene_id: Option<String>,
+    /// Whether the run in progress is a Terror Zone run.
+    pub is_tz: bool,
+    /// Session of the run in progress.
+    pub session_id: Option<String>,
+    /// Unix timestamp (milliseconds) when the run started.
+    pub started_at: Option<i64>,
+    /// Pause-adjusted elapsed time in milliseconds.
+    pub elapsed_ms: i64,
+    /// Pauses so far, oldest first.
+    pub pauses: Vec<PauseInterval>,
+    /// Elapsed time in milliseconds at each split, in order.
+    pub splits: Vec<i64>,
+    /// Drops recorded so far.
+    pub drops: Vec<String>,
+}
+
+/// Payload of [`TIMER_TICK_EVENT`].
+#[derive(Serialize, Clone, Debug)]
+pub struct TimerTick {
+    /// Pause-adjusted elapsed time in milliseconds.
+    pub elapsed_ms: i64,
+}
+
+/// The run being timed.
+struct ActiveRun {
+    /// ID of the saved record, kept in checkpoints.
+    id: String,
+    scene_id: String,
+    is_tz: bool,
+    session_id: Option<String>,
+    /// Wall-clock start, for the saved record.
+    started_at: i64,
+    /// Monotonic start of this timing segment.
+    start: Instant,
+    /// Elapsed time carried over from a restored checkpoint.
+    offset: Duration,
+    /// Total time spent in finished pauses.
+    paused_total: Duration,
+    /// Monotonic start of the current pause.
+    paused_since: Option<Instant>,
+    pauses: Vec<PauseInterval>,
+    splits: Vec<i64>,
+    drops: Vec<String>,
+    timeline: Vec<RunEvent>,
+}
+
+impl ActiveRun {
+    fn elapsed(&self, now: Instant) -> Duration {
+        let paused = self.paused_total + self.paused_since.map_or(Duration::ZERO, |p| now - p);
+        (self.offset + (now - self.start)).saturating_sub(paused)
+    }
+
+    fn elapsed_ms(&self) -> i64 {
+        self.elapsed(Instant::now()).as_millis() as i64
+    }
+
+    /// Starts a pause now.
+    fn pause(&mut self) {
+        self.paused_since = Some(Instant::now());
+        self.pauses.push(PauseInterval {
+            start: chrono::Utc::now().timestamp_millis(),
+            end: None,
+        });
+        self.record(RunEventKind::Pause);
+    }
+
+    /// Ends the pause started at `since`.
+    fn resume(&mut self, since: Instant) {
+        self.paused_since = None;
+        self.paused_total += since.elapsed();
+        if let Some(pause) = self.pauses.last_mut() {
+            pause.end = Some(chrono::Utc::now().timestamp_millis());
+        }
+        self.record(RunEventKind::Resume);
+    }
+
+    /// Records a split at the current elapsed time.
+    fn split(&mut self) {
+        let elapsed = self.elapsed_ms();
+        self.splits.push(elapsed);
+        self.record(RunEventKind::Split);
+    }
+
+    /// Appends an event at the current time to the timeline.
+    fn record(&mut self, kind: RunEventKind) {
+        let offset_ms = self.elapsed_ms();
+        self.timeline.push(RunEvent {
+            at: chrono::Utc::now().timestamp_millis(),
+            offset_ms,
+            kind,
+        });
+    }
+
+    /// Replaces the drops, recording an event for each added drop and
+    /// dropping the latest event of each removed one.
+    fn replace_drops(&mut self, drops: Vec<String>) {
+        let mut removed = std::mem::take(&mut self.drops);
+        for item in &drops {
+            match removed.iter().position(|d| d == item) {
+                Some(i) => {
+                    removed.swap_remove(i);
+                }
+                None => self.record(RunEventKind::Drop {
+                    item_id: item.clone(),
+                }),
+            }
+        }
+        for item in removed {
+            let last = self.timeline.iter().rposition(
+                |e| matches!(&e.kind, RunEventKind::Drop { item_id } if *item_id == item),
+            );
+            if let Some(i) = last {
+                self.timeline.remove(i);
+            }
+        }
+        self.drops = drops;
+    }
+}
+
+/// The run timer held in [`AppState`].
+#[derive(Default)]
+pub struct RunTimer {
+    run: Mutex<Option<ActiveRun>>,
+    /// Held while a checkpoint is written and while a finished run is saved,
+    /// so a checkpoint taken before [`RunTimer::finish`] cannot be written
+    /// after the run was saved and its checkpoint cleared.
+    saving: Mutex<()>,
+}
+
+impl RunTimer {
+    fn lock(&self) -> MutexGuard<'_, Option<ActiveRun>> {
+        self.run.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Runs `f` on the active run, failing if no run is in progress.
+    fn with_run(&self, f: impl FnOnce(&mut ActiveRun)) -> AppResult<TimerSnapshot> {
+        let mut guard = self.lock();
+        let run = guard
+            .as_mut()
+            .ok_or_else(|| AppError::Validation("no run in progress".to_string()))?;
+        f(run);
+        Ok(snapshot_of(&guard))
+    }
+
+    /// Returns the current timer state.
+    pub fn snapshot(&self) -> TimerSnapshot {
+        snapshot_of(&self.lock())
+    }
+
+    /// Returns the elapsed time of a running (not paused) run.
+    pub fn running_elapsed_ms(&self) -> Option<i64> {
+        self.lock()
+            .as_ref()
+            .filter(|r| r.paused_since.is_none())
+            .map(ActiveRun::elapsed_ms)
+    }
+
+    /// Starts a new run, discarding any run in progress.
+    ///
+    /// # Arguments
+    /// * `scene_id` - Scene of the run.
+    /// * `is_tz` - Whether this is a Terror Zone run.
+    /// * `session_id` - The session the run belongs to, if any.
+    pub fn start(
+        &self,
+        scene_id: String,
+        is_tz: bool,
+        session_id: Option<String>,
+    ) -> TimerSnapshot {
+        let started_at = chrono::Utc::now().timestamp_millis();
+        let mut guard = self.lock();
+        *guard = Some(ActiveRun {
+            id: uuid::Uuid::new_v4().to_string(),
+            scene_id,
+            is_tz,
+            session_id,
+            started_at,
+            start: Instant::now(),
+            offset: Duration::ZERO,
+            paused_total: Duration::ZERO,
+            paused_since: None,
+            pauses: Vec::new(),
+            splits: Vec::new(),
+            drops: Vec::new(),
+            timeline: vec![RunEvent {
+                at: started_at,
+                offset_ms: 0,
+                kind: RunEventKind::Start,
+            }],
+        });
+        snapshot_of(&guard)
+    }
+
+    /// Pauses the run. Pausing a paused run does nothing.
+    pub fn pause(&self) -> AppResult<TimerSnapshot> {
+        self.with_run(|run| {
+            if run.paused_since.is_none() {
+                run.pause();
+            }
+        })
+    }
+
+    /// Resumes a paused run. Resuming a running run does nothing.
+    pub fn resume(&self) -> AppResult<TimerSnapshot> {
+        self.with_run(|run| {
+            if let Some(since) = run.paused_since {
+                run.resume(since);
+            }
+        })
+    }
+
+    /// Pauses a running run or resumes a paused one.
+    ///
+    /// The phase is read and changed under one lock, so two quick toggles
+    /// never both pause or both resume.
+    pub fn toggle_pause(&self) -> AppResult<TimerSnapshot> {
+        self.with_run(|run| match run.paused_since {
+            Some(since) => run.resume(since),
+            None => run.pause(),
+        })
+    }
+
+    /// Records a split if the run is running, not paused.
+    ///
+    /// # Returns
+    /// The new state, or `None` if no run is running.
+    pub fn split_running(&self) -> Option<TimerSnapshot> {
+        let mut guard = self.lock();
+        guard.as_mut().filter(|r| r.paused_since.is_none())?.split();
+        Some(snapshot_of(&guard))
+    }
+
+    /// Records a split at the current elapsed time.
+    pub fn split(&self) -> AppResult<TimerSnapshot> {
+        self.with_run(ActiveRun::split)
+    }
+
+    /// Replaces the drops of the run in progress.
+    pub fn set_drops(&self, drops: Vec<String>) -> AppResult<TimerSnapshot> {
+        self.with_run(|run| run.replace_drops(drops))
+    }
+
+    /// Changes the Terror Zone flag of the run in progress.
+    pub fn set_tz(&self, is_tz: bool) -> AppResult<TimerSnapshot> {
+        self.with_run(|run| run.is_tz = is_tz)
+    }
+
+    /// Stops the timer and returns the finished run, not yet saved or split
+    /// into segments.
+    ///
+    /// # Returns
+    /// The run record, or `None` if no run was in progress.
+    pub fn finish(&self) -> Option<RunRecord> {
+        let mut run = self.lock().take()?;
+        let duration_ms = run.elapsed_ms();
+        run.record(RunEventKind::Finish);
+        let started = chrono::DateTime::from_timestamp_millis(run.started_at).unwrap_or_default();
+        Some(RunRecord {
+            id: run.id,
+            timestamp: run.started_at,
+            date_str: started.format("%Y-%m-%d").to_string(),
+            scene_id: run.scene_id,
+            duration_ms,
+            drops: run.drops,
+            is_tz: run.is_tz,
+            edits: Vec::new(),
+            session_id: run.session_id,
+            timeline: run.timeline,
+            segments: Vec::new(),
+        })
+    }
+
+    /// Returns a checkpoint of the run in progress.
+    pub fn checkpoint(&self) -> Option<RunCheckpoint> {
+        self.lock().as_ref().map(|run| RunCheckpoint {
+            schema_version: CHECKPOINT_SCHEMA_VERSION,
+            run_id: Some(run.id.clone()),
+            scene_id: run.scene_id.clone(),
+            is_tz: run.is_tz,
+            session_id: run.session_id.clone(),
+            started_at: run.started_at,
+            pauses: run.pauses.clone(),
+            drops: run.drops.clone(),
+            splits: run.splits.clone(),
+            timeline: run.timeline.clone(),
+            elapsed_ms: run.elapsed_ms(),
+            saved_at: chrono::Utc::now().timestamp_millis(),
+        })
+    }
+
+    /// Writes a checkpoint of the run in progress, if any.
+    ///
+    /// # Arguments
+    /// * `dir` - The application data directory path.
+    pub fn save_checkpoint(&self, dir: &Path) -> AppResult<()> {
+        let _saving = self.lock_checkpoints();
+        match self.checkpoint() {
+            Some(cp) => checkpoint::save(dir, &cp),
+            None => Ok(()),
+        }
+    }
+
+    /// Blocks checkpoint writes until the guard is dropped.
+    ///
+    /// Held from [`RunTimer::finish`] until the finished run is saved and its
+    /// checkpoint cleared.
+    pub fn lock_checkpoints(&self) -> MutexGuard<'_, ()> {
+        self.saving.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Continues a run from a checkpoint.
+    ///
+    /// Time between the checkpoint and now (e.g. while the app was closed)
+    /// is not counted. A run paused in the checkpoint stays paused, its
+    /// pause ending when the user resumes it.
+    pub fn restore(&self, checkpoint: RunCheckpoint) -> TimerSnapshot {
+        let mut timeline = checkpoint.timeline;
+        if timeline.is_empty() {
+            timeline.push(RunEvent {
+                at: checkpoint.started_at,
+                offset_ms: 0,
+                kind: RunEventKind::Start,
+            });
+        }
+        let now = Instant::now();
+        let paused = checkpoint.pauses.last().is_some_and(|p| p.end.is_none());
+        let mut guard = self.lock();
+        *guard = Some(ActiveRun {
+            id: checkpoint
+                .run_id
+                .unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+            scene_id: checkpoint.scene_id,
+            is_tz: checkpoint.is_tz,
+            session_id: checkpoint.session_id,
+            started_at: checkpoint.started_at,
+            start: now,
+            offset: Duration::from_millis(checkpoint.elapsed_ms.max(0) as u64),
+            paused_total: Duration::ZERO,
+            paused_since: paused.then_some(now),
+            pauses: checkpoint.pauses,
+            splits: checkpoint.splits,
+            drops: checkpoint.drops,
+            timeline,
+        });
+        snapshot_of(&guard)
+    }
+}
+
+fn snapshot_of(run: &Option<ActiveRun>) -> TimerSnapshot {
+    match run {
+        Some(run) => TimerSnapshot {
+            phase: if run.paused_since.is_some() {
+                TimerPhase::Paused
+            } else {
+                TimerPhase::Running
+            },
+            scene_id: Some(run.scene_id.clone()),
+            is_tz: run.is_tz,
+            session_id: run.session_id.clone(),
+            started_at: Some(run.started_at),
+            elapsed_ms: run.elapsed_ms(),
+            pauses: run.pauses.clone(),
+            splits: run.splits.clone(),
+            drops: run.drops.clone(),
+        },
+        None => TimerSnapshot {
+            phase: TimerPhase::Idle,
+            scene_id: None,
+            is_tz: false,
+            session_id: None,
+            started_at: None,
+            elapsed_ms: 0,
+            pauses: Vec::new(),
+            splits: Vec::new(),
+            drops: Vec::new(),
+        },
+    }
+}
+
+// ============================================================================
+// Event Driver
+// ============================================================================
+
+/// Emits the new state and checkpoints the run in progress.
+///
+/// Called after every transition, whether it came from a command or a hotkey.
+/// The transition already happened, so a failed checkpoint is reported with
+/// [`CHECKPOINT_ERROR_EVENT`] instead of failing the caller.
+pub fn publish(app: &AppHandle, state: &AppState, snapshot: TimerSnapshot) -> TimerSnapshot {
+    let _ = app.emit(TIMER_STATE_EVENT, &snapshot);
+    if let Err(e) = state.timer.save_checkpoint(&state.app_data_dir) {
+        let _ = app.emit(CHECKPOINT_ERROR_EVENT, e.to_string());
+    }
+    snapshot
+}
+
+/// Spawns the thread emitting tick events and periodic checkpoints.
+pub fn spawn_ticker(app: AppHandle) {
+    thread::spawn(move || {
+        let mut last_checkpoint = Instant::now();
+        // Only the first of consecutive failures is reported
+        let mut failing = false;
+        loop {
+            thread::sleep(TICK_INTERVAL);
+            let state = app.state::<AppState>();
+            let Some(elapsed_ms) = state.timer.running_elapsed_ms() else {
+                continue;
+            };
+            let _ = app.emit(TIMER_TICK_EVENT, TimerTick { elapsed_ms });
+
+            if last_checkpoint.elapsed() >= CHECKPOINT_INTERVAL {
+                last_checkpoint = Instant::now();
+                match state.timer.save_checkpoint(&state.app_data_dir) {
+                    Ok(()) => failing = false,
+                    Err(e) if !failing => {
+                        failing = true;
+                        let _ = app.emit(CHECKPOINT_ERROR_EVENT, e.to_string());
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drop_events(run: &RunRecord) -> Vec<&str> {
+        run.timeline
+            .iter()
+            .filter_map(|e| match &e.kind {
+                RunEventKind::Drop { item_id } => Some(item_id.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn drops(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|d| d.to_string()).collect()
+    }
+
+    #[test]
+    fn replacing_drops_reconciles_the_timeline() {
+        let timer = RunTimer::default();
+        timer.start("pit".to_string(), false, None);
+        timer.set_drops(drops(&["r30", "r31"])).unwrap();
+        timer.set_drops(drops(&["r31", "r32"])).unwrap();
+        // A second copy of an item is a new drop; removing one copy drops
+        // only its latest event
+        timer.set_drops(drops(&["r31", "r32", "r32"])).unwrap();
+        timer.set_drops(drops(&["r32", "r31"])).unwrap();
+
+        let run = timer.finish().unwrap();
+        assert_eq!(run.drops, drops(&["r32", "r31"]));
+        assert_eq!(drop_events(&run), ["r31", "r32"]);
+    }
+
+    #[test]
+    fn toggling_pause_alternates_and_paused_runs_are_not_split() {
+        let timer = RunTimer::default();
+        assert!(timer.toggle_pause().is_err());
+        timer.start("pit".to_string(), false, None);
+
+        assert_eq!(timer.toggle_pause().unwrap().phase, TimerPhase::Paused);
+        assert!(timer.split_running().is_none());
+        let snapshot = timer.toggle_pause().unwrap();
+        assert_eq!(snapshot.phase, TimerPhase::Running);
+        assert_eq!(snapshot.pauses.len(), 1);
+        assert!(snapshot.pauses[0].end.is_some());
+        assert_eq!(timer.split_running().unwrap().splits.len(), 1);
+    }
+
+    #[test]
+    fn restored_paused_run_stays_paused() {
+        let timer = RunTimer::default();
+        timer.restore(RunCheckpoint {
+            schema_version: CHECKPOINT_SCHEMA_VERSION,
+            run_id: Some("run-1".to_string()),
+            scene_id: "pit".to_string(),
+            is_tz: false,
+            session_id: None,
+            started_at: 1_000,
+            pauses: vec![PauseInterval {
+                start: 5_000,
+                end: None,
+            }],
+            drops: Vec::new(),
+            splits: Vec::new(),
+            timeline: vec![
+                RunEvent {
+                    at: 1_000,
+                    offset_ms: 0,
+                    kind: RunEventKind::Start,
+                },
+                RunEvent {
+                    at: 5_000,
+                    offset_ms: 4_000,
+                    kind: RunEventKind::Pause,
+                },
+            ],
+            elapsed_ms: 4_000,
+            saved_at: 8_000,
+        });
+        let snapshot = timer.snapshot();
+        assert_eq!(snapshot.phase, TimerPhase::Paused);
+        assert_eq!(snapshot.elapsed_ms, 4_000);
+        assert_eq!(snapshot.pauses[0].end, None);
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(timer.snapshot().elapsed_ms, 4_000);
+        assert_eq!(timer.running_elapsed_ms(), None);
+
+        let snapshot = timer.resume().unwrap();
+        assert_eq!(snapshot.phase, TimerPhase::Running);
+        assert!(snapshot.pauses[0].end.is_some());
+        let run = timer.finish().unwrap();
+        assert_eq!(run.id, "run-1");
+        assert!(run.duration_ms >= 4_000);
+        let kinds: Vec<&RunEventKind> = run.timeline.iter().map(|e| &e.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                &RunEventKind::Start,
+                &RunEventKind::Pause,
+                &RunEventKind::Resume,
+                &RunEventKind::Finish
+            ]
+        );
+    }
+}
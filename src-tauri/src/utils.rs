@@ -3,8 +3,21 @@
 //! This module provides file system helpers and the global application state
 //! used across all Tauri commands.
 
-use std::fs;
-use std::path::PathBuf;
+use crate::cache::RunCache;
+use crate::custom_items::QualityIndex;
+use crate::db::Database;
+use crate::error::{AppError, AppResult};
+use crate::hotkeys::{self, Hotkeys};
+use crate::migrations::{migrate_config, schema_version, CONFIG_SCHEMA_VERSION};
+use crate::models::{AppConfig, DEFAULT_TRASH_RETENTION_DAYS};
+use crate::timer::RunTimer;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{RwLock, RwLockReadGuard};
 use tauri::{AppHandle, Manager};
 
 /// Global application state managed by Tauri.
@@ -14,19 +27,69 @@ use tauri::{AppHandle, Manager};
 pub struct AppState {
     /// Path to the application data directory where runs and config are stored.
     pub app_data_dir: PathBuf,
+    /// SQLite database holding local and cached cloud runs.
+    pub db: Database,
+    /// In-memory copy of the local runs, written through to `db`.
+    pub runs: RunCache,
+    /// Quality of every custom item, reloaded after the items change.
+    pub qualities: QualityIndex,
+    /// Timer of the run in progress.
+    pub timer: RunTimer,
+    /// Global shortcut bindings and recording mode.
+    pub hotkeys: Hotkeys,
+    /// Configuration as last loaded or saved, so commands need not read the file.
+    pub config: RwLock<AppConfig>,
 }
 
-/// Returns the path to the runs data file.
+impl AppState {
+    /// Returns the configuration as last loaded or saved.
+    pub fn config(&self) -> RwLockReadGuard<'_, AppConfig> {
+        self.config.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Replaces the configuration held in memory.
+    pub fn set_config(&self, config: AppConfig) {
+        *self.config.write().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    /// Permanently deletes trashed runs older than the configured retention.
+    ///
+    /// # Returns
+    /// The number of runs purged.
+    pub fn purge_expired_trash(&self) -> AppResult<usize> {
+        let days = self
+            .config()
+            .trash_retention_days
+            .unwrap_or(DEFAULT_TRASH_RETENTION_DAYS);
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(days.into());
+        Ok(self.db.purge_trash(cutoff.timestamp_millis())?)
+    }
+}
+
+/// Returns the path to the legacy runs data file.
+///
+/// Only read once, when importing into the SQLite database.
 ///
 /// # Arguments
 /// * `dir` - The application data directory path.
 ///
 /// # Returns
 /// The full path to `runs.json`.
-pub fn get_runs_path(dir: &PathBuf) -> PathBuf {
+pub fn get_runs_path(dir: &Path) -> PathBuf {
     dir.join("runs.json")
 }
 
+/// Returns the path to the SQLite run database.
+///
+/// # Arguments
+/// * `dir` - The application data directory path.
+///
+/// # Returns
+/// The full path to `runs.db`.
+pub fn get_db_path(dir: &Path) -> PathBuf {
+    dir.join("runs.db")
+}
+
 /// Returns the path to the configuration file.
 ///
 /// # Arguments
@@ -34,21 +97,201 @@ pub fn get_runs_path(dir: &PathBuf) -> PathBuf {
 ///
 /// # Returns
 /// The full path to `config.json`.
-pub fn get_config_path(dir: &PathBuf) -> PathBuf {
+pub fn get_config_path(dir: &Path) -> PathBuf {
     dir.join("config.json")
 }
 
-/// Returns the path to the cloud runs data file.
+/// Returns the path to the legacy cloud runs data file.
+///
+/// Only read once, when importing into the SQLite database.
 ///
 /// # Arguments
 /// * `dir` - The application data directory path.
 ///
 /// # Returns
 /// The full path to `runs_cloud.json`.
-pub fn get_cloud_runs_path(dir: &PathBuf) -> PathBuf {
+pub fn get_cloud_runs_path(dir: &Path) -> PathBuf {
     dir.join("runs_cloud.json")
 }
 
+/// Returns the path to the in-flight run checkpoint.
+///
+/// # Arguments
+/// * `dir` - The application data directory path.
+///
+/// # Returns
+/// The full path to `active_run.json`.
+pub fn get_checkpoint_path(dir: &Path) -> PathBuf {
+    dir.join("active_run.json")
+}
+
+/// Writes a file atomically.
+///
+/// The contents are written to a temporary file next to `path`, flushed to
+/// disk, and then renamed over the target. A crash mid-write leaves either
+/// the old file or the new one, never a truncated mix. Each write uses its own
+/// temporary file, so concurrent writes to the same path cannot interleave.
+///
+/// # Arguments
+/// * `path` - The file to write.
+/// * `contents` - The bytes to write.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    static NEXT_TMP: AtomicU64 = AtomicU64::new(0);
+
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp_path = path.with_file_name(format!(
+        "{}.tmp-{}-{}",
+        file_name,
+        std::process::id(),
+        NEXT_TMP.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let written = File::create(&tmp_path).and_then(|mut file| {
+        file.write_all(contents.as_ref())?;
+        file.sync_all()
+    });
+    if let Err(e) = written.and_then(|_| fs::rename(&tmp_path, path)) {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e);
+    }
+
+    // Persist the rename itself. Directories cannot be opened this way on Windows,
+    // where the rename is already durable once it returns.
+    #[cfg(unix)]
+    if let Some(parent) = path.parent() {
+        File::open(parent)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// Moves a corrupted file aside so it is not overwritten by the next save.
+///
+/// The file is renamed to `<name>.corrupt-<timestamp>` in the same directory,
+/// with a counter appended if that name is already taken.
+///
+/// # Arguments
+/// * `path` - The corrupted file.
+///
+/// # Returns
+/// The path the file was moved to.
+pub fn quarantine_file(path: &Path) -> io::Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S%3f");
+    let base = format!("{}.corrupt-{}", file_name, stamp);
+    let mut target = path.with_file_name(&base);
+    let mut counter = 1;
+    while target.exists() {
+        target = path.with_file_name(format!("{}-{}", base, counter));
+        counter += 1;
+    }
+
+    fs::rename(path, &target)?;
+    Ok(target)
+}
+
+/// Reads and parses a JSON file.
+///
+/// If the file exists but cannot be parsed (e.g. it was truncated by a crash),
+/// it is moved aside with [`quarantine_file`] instead of being treated as empty.
+///
+/// # Arguments
+/// * `path` - The JSON file to read.
+///
+/// # Returns
+/// The parsed value, `None` if the file does not exist, or an error if the
+/// file could not be read or was corrupted.
+pub fn load_json<T: DeserializeOwned>(path: &Path) -> AppResult<Option<T>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(path)?;
+    match serde_json::from_str(&content) {
+        Ok(value) => Ok(Some(value)),
+        Err(e) => {
+            let moved_to = quarantine_file(path)?;
+            Err(AppError::Corrupted(format!(
+                "{} could not be parsed ({}) and was moved to {}",
+                path.display(),
+                e,
+                moved_to.display()
+            )))
+        }
+    }
+}
+
+/// Loads the application configuration from the data directory.
+///
+/// Backs the `get_config` command, which documents the fallback rules.
+///
+/// # Arguments
+/// * `dir` - The application data directory path.
+///
+/// # Returns
+/// The current application configuration.
+pub fn load_config(dir: &Path) -> AppResult<AppConfig> {
+    let path = get_config_path(dir);
+
+    let config = match load_json::<Value>(&path)? {
+        Some(value) => {
+            let outdated = schema_version(&value) < CONFIG_SCHEMA_VERSION;
+            let config = config_from_value(value)?;
+            if outdated {
+                // Persist the upgrade so the file on disk carries the current version
+                write_atomic(&path, serde_json::to_string_pretty(&config)?)?;
+            }
+            config
+        }
+        None => AppConfig::default(),
+    };
+    Ok(config)
+}
+
+/// Reads the application configuration without touching the file.
+///
+/// Unlike [`load_config`], an outdated file is not written back and a
+/// corrupted one is not moved aside, so `get_config` still reports it to the
+/// frontend later.
+///
+/// # Arguments
+/// * `dir` - The application data directory path.
+///
+/// # Returns
+/// The current application configuration, or the default one if no file exists.
+pub fn read_config(dir: &Path) -> AppResult<AppConfig> {
+    let path = get_config_path(dir);
+    if !path.exists() {
+        return Ok(AppConfig::default());
+    }
+    let value: Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+    config_from_value(value)
+}
+
+/// Upgrades a parsed configuration file to the current schema.
+fn config_from_value(value: Value) -> AppResult<AppConfig> {
+    let mut config: AppConfig = serde_json::from_value(migrate_config(value)?)?;
+
+    // Ensure every action has a shortcut, e.g. ones added in a later release,
+    // unless the user already bound its keys to another action
+    for (action, binding) in AppConfig::default().shortcuts {
+        let taken = config
+            .shortcuts
+            .values()
+            .any(|b| hotkeys::same_keys(b, &binding));
+        if !taken {
+            config.shortcuts.entry(action).or_insert(binding);
+        }
+    }
+    Ok(config)
+}
+
 /// Ensures the application data directory exists, creating it if necessary.
 ///
 /// # Arguments
@@ -69,3 +312,44 @@ pub fn ensure_dir(app_handle: &AppHandle) -> PathBuf {
     }
     dir
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_default_shortcuts_do_not_take_used_keys() {
+        let mut config = AppConfig::default();
+        config.shortcuts.remove("SPLIT");
+        config.shortcuts.remove("FINISH_SESSION");
+        // The user already put Alt+S (the SPLIT default) on another action
+        config.shortcuts.get_mut("OPEN_SEARCH").unwrap().name = "KeyS".to_string();
+
+        let config = config_from_value(serde_json::to_value(config).unwrap()).unwrap();
+        assert!(!config.shortcuts.contains_key("SPLIT"));
+        assert_eq!(config.shortcuts["FINISH_SESSION"].name, "Return");
+    }
+
+    #[test]
+    fn concurrent_atomic_writes_do_not_share_a_temp_file() {
+        let dir = std::env::temp_dir().join(format!("d2run-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+
+        let contents: Vec<String> = (0..8).map(|i| i.to_string().repeat(4096)).collect();
+        std::thread::scope(|scope| {
+            for c in &contents {
+                let path = &path;
+                scope.spawn(move || {
+                    for _ in 0..20 {
+                        write_atomic(path, c).unwrap();
+                    }
+                });
+            }
+        });
+
+        assert!(contents.contains(&fs::read_to_string(&path).unwrap()));
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
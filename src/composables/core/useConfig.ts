@@ -5,9 +5,12 @@
  */
 
 import { ref, type Ref } from 'vue';
-import type { AppConfig, KeyBinding } from '../../shared/types';
+import type { AppConfig, KeyBinding, KeyTrigger, KeyTriggerKind, ShortcutDiagnostic } from '../../shared/types';
+import { DEFAULT_DOUBLE_TAP_MS, DEFAULT_HOLD_MS, DEFAULT_SEQUENCE_MS } from '../../shared/constants';
 import { invoke } from '@tauri-apps/api/core';
 import type { UseThemeReturn } from './useTheme';
+import { useToast } from './useToast';
+import { describeError } from '../../shared/utils';
 
 /** Config composable return interface */
 export interface UseConfigReturn {
@@ -15,6 +18,8 @@ export interface UseConfigReturn {
     config: Ref<AppConfig | null>;
     /** Currently recording shortcut key */
     recordingKey: Ref<string | null>;
+    /** Issues with the current shortcuts */
+    shortcutDiagnostics: Ref<ShortcutDiagnostic[]>;
     /** Load configuration from backend */
     loadConfig: (themeComposable: UseThemeReturn) => Promise<void>;
     /** Reset settings to defaults */
@@ -24,9 +29,11 @@ export interface UseConfigReturn {
         currentView: string
     ) => Promise<void>;
     /** Start recording a shortcut key */
-    startRecording: (actionKey: string) => Promise<void>;
+    startRecording: (actionKey: string, kind?: KeyTriggerKind) => Promise<void>;
     /** Apply recorded key binding */
     applyRecordedKey: (binding: KeyBinding) => Promise<void>;
+    /** Change how a shortcut key has to be pressed */
+    setShortcutTrigger: (action: string, kind: KeyTriggerKind) => Promise<void>;
     /** Cancel key recording */
     cancelRecording: () => void;
     /** Save custom view size */
@@ -39,16 +46,36 @@ export interface UseConfigReturn {
 export function useConfig(): UseConfigReturn {
     const config = ref<AppConfig | null>(null);
     const recordingKey = ref<string | null>(null);
+    const shortcutDiagnostics = ref<ShortcutDiagnostic[]>([]);
+
+    /**
+     * Refresh the diagnostics of the current shortcuts
+     */
+    async function checkShortcuts(): Promise<void> {
+        if (!config.value) return;
+        try {
+            shortcutDiagnostics.value = await invoke('validate_shortcuts', { shortcuts: config.value.shortcuts });
+        } catch (e) {
+            console.error(e);
+        }
+    }
 
     /**
      * Load configuration from backend
      */
     async function loadConfig(themeComposable: UseThemeReturn): Promise<void> {
         try {
-            config.value = await invoke('get_config');
+            try {
+                config.value = await invoke('get_config');
+            } catch (e) {
+                // A corrupted file has been moved aside; the next call returns defaults
+                useToast().error(describeError(e), 5000);
+                config.value = await invoke('get_config');
+            }
             if (config.value) {
                 // Load theme settings from config via composable
                 themeComposable.loadThemeFromConfig(config.value);
+                await checkShortcuts();
             }
         } catch (e) {
             console.error(e);
@@ -80,6 +107,8 @@ export function useConfig(): UseConfigReturn {
                     await invoke('save_config', { config: JSON.parse(JSON.stringify(config.value)) });
                 }
 
+                await checkShortcuts();
+
                 // Resize current view to default size
                 tryResize(currentView);
             }
@@ -88,12 +117,71 @@ export function useConfig(): UseConfigReturn {
         }
     }
 
+    /**
+     * Build the trigger of a kind, keeping the timing of the current one
+     */
+    function triggerOf(kind: KeyTriggerKind, current?: KeyTrigger): KeyTrigger {
+        if (current?.kind === kind) return current;
+        switch (kind) {
+            case 'double_tap':
+                return { kind, within_ms: DEFAULT_DOUBLE_TAP_MS };
+            case 'hold':
+                return { kind, hold_ms: DEFAULT_HOLD_MS };
+            case 'sequence':
+                return { kind, first: '', within_ms: DEFAULT_SEQUENCE_MS };
+            default:
+                return { kind: 'press' };
+        }
+    }
+
+    /**
+     * Save one shortcut
+     *
+     * The backend rejects bindings that can never fire or are already used by
+     * another action; the previous binding is kept then.
+     */
+    async function saveShortcut(action: string, binding: KeyBinding): Promise<void> {
+        if (!config.value) return;
+        const previous = config.value.shortcuts[action];
+        config.value.shortcuts[action] = binding;
+        try {
+            await invoke('save_config', { config: JSON.parse(JSON.stringify(config.value)) });
+        } catch (e) {
+            config.value.shortcuts[action] = previous;
+            useToast().error(describeError(e), 5000);
+        }
+        await checkShortcuts();
+    }
+
     /**
      * Start recording a shortcut key
+     *
+     * The backend captures the next key combination pressed anywhere (or the
+     * next two for a sequence) and resolves with it, or with null when
+     * cancelled (Escape) or timed out.
+     *
+     * @param actionKey - Action to bind
+     * @param kind - How the key has to be pressed; defaults to the current trigger
      */
-    async function startRecording(actionKey: string): Promise<void> {
+    async function startRecording(actionKey: string, kind?: KeyTriggerKind): Promise<void> {
+        const current = config.value?.shortcuts[actionKey]?.trigger;
+        const triggerKind = kind ?? current?.kind ?? 'press';
         recordingKey.value = actionKey;
-        invoke('start_record_key');
+        try {
+            const binding = await invoke<KeyBinding | null>('start_record_key', {
+                sequence: triggerKind === 'sequence'
+            });
+            // A newer recording (or a cancel) may have taken over meanwhile
+            if (recordingKey.value !== actionKey) return;
+            if (binding) {
+                if (triggerKind !== 'sequence') binding.trigger = triggerOf(triggerKind, current);
+                await applyRecordedKey(binding);
+            }
+        } catch (e) {
+            console.error(e);
+        } finally {
+            if (recordingKey.value === actionKey) recordingKey.value = null;
+        }
     }
 
     /**
@@ -101,10 +189,24 @@ export function useConfig(): UseConfigReturn {
      */
     async function applyRecordedKey(binding: KeyBinding): Promise<void> {
         if (!config.value || !recordingKey.value) return;
-        config.value.shortcuts[recordingKey.value] = binding;
-        await invoke('stop_record_key');
-        await invoke('save_config', { config: JSON.parse(JSON.stringify(config.value)) });
+        const action = recordingKey.value;
         recordingKey.value = null;
+        await saveShortcut(action, binding);
+    }
+
+    /**
+     * Change how a shortcut key has to be pressed
+     *
+     * A sequence needs a first key, so it is recorded anew.
+     */
+    async function setShortcutTrigger(action: string, kind: KeyTriggerKind): Promise<void> {
+        const binding = config.value?.shortcuts[action];
+        if (!binding) return;
+        if (kind === 'sequence') {
+            await startRecording(action, kind);
+            return;
+        }
+        await saveShortcut(action, { ...binding, trigger: triggerOf(kind, binding.trigger) });
     }
 
     /**
@@ -130,10 +232,12 @@ export function useConfig(): UseConfigReturn {
     return {
         config,
         recordingKey,
+        shortcutDiagnostics,
         loadConfig,
         resetSettings,
         startRecording,
         applyRecordedKey,
+        setShortcutTrigger,
         cancelRecording,
         saveCustomViewSize
     };
@@ -1,76 +1,115 @@
+/**
+ * Run timer composable
+ *
+ * Mirrors the backend run timer, which is the source of truth for elapsed
+ * time and pause intervals. State is updated from `timer-state` and
+ * `timer-tick` events.
+ */
+
 import { ref, type Ref } from 'vue';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import type { RunRecord, TimerSnapshot, TimerTick } from '../../shared/types';
 
 export interface UseTimerReturn {
+    /** Whether a run is in progress (running or paused) */
     isRunning: Ref<boolean>;
+    /** Whether the user paused the run (other pause sources are tracked by the caller) */
     isUserPaused: Ref<boolean>;
+    /** Unix timestamp (ms) when the run started */
     startTime: Ref<number>;
-    totalPausedTime: Ref<number>;
-    pauseStartTime: Ref<number>;
+    /** Pause-adjusted elapsed time in ms */
     elapsedTime: Ref<number>;
-    start: () => void;
-    stop: () => void;
-    startTicker: (checkPaused: () => boolean) => void;
+    /** Elapsed time (ms) at each split of the current run */
+    splits: Ref<number[]>;
+    /** Start timing a new run */
+    start: (sceneId: string, isTz: boolean, sessionId: string | null) => Promise<void>;
+    /** Pause or resume the run */
+    setPaused: (paused: boolean) => Promise<void>;
+    /** Record a split at the current time */
+    split: () => Promise<void>;
+    /** Replace the drops of the run in progress */
+    setDrops: (drops: string[]) => Promise<void>;
+    /** Change the Terror Zone flag of the run in progress */
+    setTz: (isTz: boolean) => Promise<void>;
+    /** Stop the timer, saving the run if it lasted at least `minDurationMs` */
+    finish: (minDurationMs: number) => Promise<RunRecord | null>;
+    /** Continue the run left unfinished by the last app exit */
+    restorePending: () => Promise<TimerSnapshot | null>;
 }
 
 export function useTimer(): UseTimerReturn {
     const isRunning = ref(false);
     const isUserPaused = ref(false);
-    // Separate search pause state managed by consumer
-
     const startTime = ref(0);
-    const totalPausedTime = ref(0);
-    const pauseStartTime = ref(0);
     const elapsedTime = ref(0);
-    const timerId = ref<number | null>(null);
+    const splits = ref<number[]>([]);
+
+    function applySnapshot(snapshot: TimerSnapshot) {
+        isRunning.value = snapshot.phase !== 'idle';
+        startTime.value = snapshot.started_at ?? 0;
+        elapsedTime.value = snapshot.elapsed_ms;
+        splits.value = snapshot.splits;
+    }
+
+    listen<TimerSnapshot>('timer-state', (event) => applySnapshot(event.payload));
+    listen<TimerTick>('timer-tick', (event) => {
+        elapsedTime.value = event.payload.elapsed_ms;
+    });
+    invoke<TimerSnapshot>('timer_state').then(applySnapshot).catch(console.error);
 
-    /** Start a new timer run */
-    function start() {
-        stop();
+    async function start(sceneId: string, isTz: boolean, sessionId: string | null) {
         isUserPaused.value = false;
-        startTime.value = Date.now();
-        totalPausedTime.value = 0;
-        pauseStartTime.value = 0;
-        elapsedTime.value = 0;
-        isRunning.value = true;
+        applySnapshot(await invoke<TimerSnapshot>('timer_start', { sceneId, isTz, sessionId }));
+    }
 
-        timerId.value = window.setInterval(() => {
-            // Consumer handles checking "effective pause" state before updating
-            // This composable just ticks
-            // Logic moved to usage site or we pass `isPaused` in
-        }, 50);
+    async function setPaused(paused: boolean) {
+        if (!isRunning.value) return;
+        applySnapshot(await invoke<TimerSnapshot>(paused ? 'timer_pause' : 'timer_resume'));
     }
 
-    // Re-designing to accept external "isEffectivePaused" to make it pure?
-    // Or just provide the primitives. 
+    async function split() {
+        if (!isRunning.value) return;
+        applySnapshot(await invoke<TimerSnapshot>('timer_split'));
+    }
 
-    // Let's copy logic from runStore but clean up:
+    async function setDrops(drops: string[]) {
+        if (!isRunning.value) return;
+        await invoke('timer_set_drops', { drops });
+    }
 
-    function startTicker(checkPaused: () => boolean) {
-        if (timerId.value) clearInterval(timerId.value);
-        timerId.value = window.setInterval(() => {
-            if (!checkPaused()) {
-                elapsedTime.value = Date.now() - startTime.value - totalPausedTime.value;
-            }
-        }, 50);
+    async function setTz(isTz: boolean) {
+        if (!isRunning.value) return;
+        await invoke('timer_set_tz', { isTz });
     }
 
-    function stop() {
-        if (timerId.value) {
-            clearInterval(timerId.value);
-            timerId.value = null;
-        }
+    async function finish(minDurationMs: number): Promise<RunRecord | null> {
         isRunning.value = false;
+        return await invoke<RunRecord | null>('timer_finish', { minDurationMs });
+    }
+
+    async function restorePending(): Promise<TimerSnapshot | null> {
+        const snapshot = await invoke<TimerSnapshot | null>('restore_pending_run');
+        if (snapshot) {
+            // A run paused before the app closed is restored paused
+            isUserPaused.value = snapshot.phase === 'paused';
+            applySnapshot(snapshot);
+        }
+        return snapshot;
     }
 
     return {
         isRunning,
         isUserPaused,
         startTime,
-        totalPausedTime,
-        pauseStartTime,
         elapsedTime,
+        splits,
         start,
-        stop,
-        startTicker
+        setPaused,
+        split,
+        setDrops,
+        setTz,
+        finish,
+        restorePending
     };
 }
@@ -5,9 +5,8 @@
  */
 
 import { invoke } from '@tauri-apps/api/core';
-import type { ComputedRef } from 'vue';
-import type { RunRecord } from '../../shared/types';
-import { SCENES, ITEMS } from '../../shared/data';
+import type { ComputedRef, Ref } from 'vue';
+import type { ItemIndex, RunRecord, Scene } from '../../shared/types';
 import { DASHBOARD_HTML } from '../../shared/dashboardTemplate';
 
 /** Dashboard composable return interface */
@@ -19,29 +18,31 @@ export interface UseDashboardReturn {
 /**
  * Create dashboard composable instance
  * 
- * @param historyRecords - Computed ref to history records
- * @param loadHistory - Function to ensure history is loaded
+ * @param fetchAllRecords - Function fetching every run matching the history filter
+ * @param scenes - Ref to the scene registry
+ * @param items - Catalog and custom items
  */
 export function useDashboard(
-    historyRecords: ComputedRef<RunRecord[]>,
-    loadHistory: () => Promise<void>
+    fetchAllRecords: () => Promise<RunRecord[]>,
+    scenes: Ref<Scene[]>,
+    items: ComputedRef<ItemIndex[]>
 ): UseDashboardReturn {
 
     async function openDashboard(initialView = 'report') {
-        const allRuns = [...historyRecords.value];
-        // If no records, try loading content first (though caller usually handles this)
-        if (allRuns.length === 0) {
-            await loadHistory();
+        // The history view only holds the pages loaded so far
+        let currentRuns: RunRecord[];
+        try {
+            currentRuns = await fetchAllRecords();
+        } catch (e) {
+            console.error('Failed to load runs for dashboard:', e);
+            return;
         }
 
-        // Re-fetch to get latest if loaded
-        const currentRuns = [...historyRecords.value];
-
         const dataContent = `
       const RUN_DATA = {
           runs: ${JSON.stringify(currentRuns)},
-          scenes: ${JSON.stringify(SCENES)},
-          items: ${JSON.stringify(ITEMS)},
+          scenes: ${JSON.stringify(scenes.value)},
+          items: ${JSON.stringify(items.value)},
           initialView: '${initialView}'
       };
     `;
@@ -2,53 +2,111 @@
  * History management composable
  *
  * Handles run history loading, filtering, and deletion.
+ * Local runs are fetched a page at a time; totals come from the backend.
  */
 
 import { ref, computed, type Ref, type ComputedRef } from 'vue';
-import type { RunRecord, HistoryFilter } from '../../shared/types';
+import type { RunRecord, RunPage, RunStats, HistoryFilter } from '../../shared/types';
 import { invoke } from '@tauri-apps/api/core';
 import type { UseCloudSyncReturn } from './useCloudSync';
 import { ensureCloudReady } from './useCloudSync';
+import { HISTORY_PAGE_SIZE } from '../../shared/constants';
 
 /** History composable return interface */
 export interface UseHistoryReturn {
-    /** Local records from database */
+    /** Local records loaded so far, newest first */
     localRecords: Ref<RunRecord[]>;
     /** History filter state */
     historyFilter: Ref<HistoryFilter>;
-    /** Merged history records (local + cloud, deduplicated) */
+    /** Loaded history records (local + cloud, deduplicated), newest first */
     historyRecords: ComputedRef<RunRecord[]>;
-    /** Load history with current filter */
+    /** Number of runs matching the filter, loaded or not */
+    historyTotal: ComputedRef<number>;
+    /** Backend statistics over all local runs matching the filter */
+    historyStats: Ref<RunStats | null>;
+    /** Whether more local runs can be loaded */
+    hasMoreHistory: ComputedRef<boolean>;
+    /** Load the first page of history with current filter */
     loadHistory: () => Promise<void>;
+    /** Load the next page of local runs */
+    loadMoreHistory: () => Promise<void>;
+    /** Fetch every record matching the current filter, e.g. for the dashboard */
+    fetchAllRecords: () => Promise<RunRecord[]>;
     /** Delete a run by ID */
     deleteRun: (id: string) => Promise<void>;
     /** Clear local records (for refresh) */
     clearLocalRecords: () => void;
 }
 
+/**
+ * Merge local and cloud records, newest first
+ *
+ * Records with the same timestamp are the same run; the cloud copy wins.
+ */
+function mergeRecords(local: RunRecord[], cloud: RunRecord[]): RunRecord[] {
+    if (cloud.length === 0) return local;
+    const unique = new Map<number, RunRecord>();
+    [...local, ...cloud].forEach(r => {
+        if (!unique.has(r.timestamp) || r.id.startsWith('cloud_')) {
+            unique.set(r.timestamp, r);
+        }
+    });
+    return Array.from(unique.values()).sort((a, b) => b.timestamp - a.timestamp);
+}
+
 /**
  * Create history composable instance
  * @param cloudSync - Cloud sync composable for cloud records integration
  */
 export function useHistory(cloudSync: UseCloudSyncReturn): UseHistoryReturn {
     const localRecords = ref<RunRecord[]>([]);
+    const localTotal = ref(0);
+    const historyStats = ref<RunStats | null>(null);
     const historyFilter = ref<HistoryFilter>({ startStr: '', endStr: '', sceneId: 'all' });
 
-    // Merge local and cloud records for history display
-    const historyRecords = computed(() => {
-        const all = [...localRecords.value, ...cloudSync.cloudRecords.value];
-        // Dedupe by timestamp (cloud records take priority)
-        const unique = new Map<number, RunRecord>();
-        all.forEach(r => {
-            if (!unique.has(r.timestamp) || r.id.startsWith('cloud_')) {
-                unique.set(r.timestamp, r);
-            }
-        });
-        return Array.from(unique.values()).sort((a, b) => b.timestamp - a.timestamp);
+    const hasMoreHistory = computed(() => localRecords.value.length < localTotal.value);
+
+    // Cloud records are held in full, so only those within the loaded time
+    // window are shown until older local pages are loaded
+    const visibleCloudRecords = computed(() => {
+        const cloud = cloudSync.cloudRecords.value;
+        const local = localRecords.value;
+        if (!hasMoreHistory.value || local.length === 0) return cloud;
+        const oldest = local[local.length - 1].timestamp;
+        return cloud.filter(r => r.timestamp >= oldest);
     });
 
+    const historyRecords = computed(() => mergeRecords(localRecords.value, visibleCloudRecords.value));
+
+    const historyTotal = computed(() => {
+        const localStamps = new Set(localRecords.value.map(r => r.timestamp));
+        const cloudOnly = cloudSync.cloudRecords.value.filter(r => !localStamps.has(r.timestamp));
+        return localTotal.value + cloudOnly.length;
+    });
+
+    /**
+     * Filter in the shape the backend expects ('all' and empty dates unset)
+     */
+    function backendFilter(): HistoryFilter {
+        const filter = { ...historyFilter.value };
+        if (filter.sceneId === 'all') filter.sceneId = undefined;
+        if (!filter.startStr) filter.startStr = undefined;
+        if (!filter.endStr) filter.endStr = undefined;
+        return filter;
+    }
+
     /**
-     * Load history with current filter
+     * Fetch one page of local runs, newest first
+     */
+    function fetchPage(filter: HistoryFilter, offset: number): Promise<RunPage> {
+        return invoke<RunPage>('get_runs', {
+            filter,
+            query: { sortBy: 'timestamp', sortDir: 'desc', offset, limit: HISTORY_PAGE_SIZE }
+        });
+    }
+
+    /**
+     * Load the first page of history with current filter
      */
     async function loadHistory(): Promise<void> {
         // Ensure cloud implementation is loaded before accessing cloud records
@@ -58,18 +116,17 @@ export function useHistory(cloudSync: UseCloudSyncReturn): UseHistoryReturn {
         const { useCloudSync } = await import('./useCloudSync');
         const currentCloudSync = useCloudSync();
 
-        const filter = { ...historyFilter.value };
-        // Convert 'all' to undefined for backend
-        if (filter.sceneId === 'all') filter.sceneId = undefined;
-        if (!filter.startStr) filter.startStr = undefined;
-        if (!filter.endStr) filter.endStr = undefined;
+        const filter = backendFilter();
 
         try {
-            const [localRuns, cloudRunList] = await Promise.all([
-                invoke('get_runs', { filter }),
+            const [page, stats, cloudRunList] = await Promise.all([
+                fetchPage(filter, 0),
+                invoke<RunStats>('get_stats', { filter }),
                 currentCloudSync.getCloudRecords({ ...filter })
             ]);
-            localRecords.value = localRuns as RunRecord[];
+            localRecords.value = page.runs;
+            localTotal.value = page.total;
+            historyStats.value = stats;
             // Update cloudRecords on the correct instance
             currentCloudSync.cloudRecords.value = cloudRunList;
             // Also sync back to passed-in cloudSync for reactivity
@@ -79,6 +136,31 @@ export function useHistory(cloudSync: UseCloudSyncReturn): UseHistoryReturn {
         }
     }
 
+    /**
+     * Load the next page of local runs
+     */
+    async function loadMoreHistory(): Promise<void> {
+        if (!hasMoreHistory.value) return;
+        try {
+            const page = await fetchPage(backendFilter(), localRecords.value.length);
+            localRecords.value = [...localRecords.value, ...page.runs];
+            localTotal.value = page.total;
+        } catch (e) {
+            console.error('Failed to load more history', e);
+        }
+    }
+
+    /**
+     * Fetch every record matching the current filter
+     */
+    async function fetchAllRecords(): Promise<RunRecord[]> {
+        const page = await invoke<RunPage>('get_runs', {
+            filter: backendFilter(),
+            query: { sortBy: 'timestamp', sortDir: 'desc' }
+        });
+        return mergeRecords(page.runs, cloudSync.cloudRecords.value);
+    }
+
     /**
      * Delete a run by ID
      */
@@ -96,13 +178,20 @@ export function useHistory(cloudSync: UseCloudSyncReturn): UseHistoryReturn {
      */
     function clearLocalRecords(): void {
         localRecords.value = [];
+        localTotal.value = 0;
+        historyStats.value = null;
     }
 
     return {
         localRecords,
         historyFilter,
         historyRecords,
+        historyTotal,
+        historyStats,
+        hasMoreHistory,
         loadHistory,
+        loadMoreHistory,
+        fetchAllRecords,
         deleteRun,
         clearLocalRecords
     };
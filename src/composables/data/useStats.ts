@@ -6,8 +6,7 @@
  */
 
 import { computed, type Ref, type ComputedRef } from 'vue';
-import type { RunRecord, Scene, ItemIndex } from '../../shared/types';
-import { SCENES, ITEMS } from '../../shared/data';
+import type { RunRecord, RunStats, Scene, ItemIndex } from '../../shared/types';
 
 /** Drop history entry for display */
 export interface DropHistoryEntry {
@@ -68,21 +67,35 @@ export interface UseStatsReturn {
 /**
  * Create statistics composable instance
  *
- * @param historyRecords - Ref to history records array
+ * Totals come from the backend statistics when loaded, since the history
+ * records only hold the pages fetched so far.
+ *
+ * @param historyRecords - Ref to the loaded history records, newest first
+ * @param historyTotal - Ref to the number of runs matching the filter
+ * @param historyStats - Ref to the backend statistics for the filter
  * @param currentLang - Ref to current language ('CN' | 'EN')
  * @param getSceneName - Function to get localized scene name
  * @param getItem - Function to get item by ID
+ * @param catalog - Ref to the item catalog, used for grail progress
  */
 export function useStats(
     historyRecords: Ref<RunRecord[]>,
+    historyTotal: Ref<number>,
+    historyStats: Ref<RunStats | null>,
     currentLang: Ref<'CN' | 'EN'>,
     getSceneName: (scene: Scene | string) => string,
-    getItem: (id: string) => ItemIndex | undefined
+    getItem: (id: string) => ItemIndex | undefined,
+    catalog: Ref<ItemIndex[]>
 ): UseStatsReturn {
 
-    const totalRuns = computed(() => historyRecords.value.length);
+    const totalRuns = computed(() => historyTotal.value);
 
     const sceneStats = computed(() => {
+        const stats = historyStats.value;
+        if (stats) {
+            if (stats.overall.count === 0) return { best: Infinity, avg: 0 };
+            return { best: stats.overall.best_ms ?? Infinity, avg: stats.overall.mean_ms };
+        }
         const runs = historyRecords.value;
         if (runs.length === 0) return { best: Infinity, avg: 0 };
         let sum = 0,
@@ -95,6 +108,19 @@ export function useStats(
     });
 
     const detailedStats = computed<DetailedStats>(() => {
+        const stats = historyStats.value;
+        if (stats && stats.overall.count > 0) {
+            return {
+                totalRuns: stats.overall.count,
+                totalTime: stats.overall.total_ms,
+                best: stats.overall.best_ms ?? Infinity,
+                worst: stats.overall.worst_ms ?? 0,
+                avg: stats.overall.mean_ms,
+                totalDrops: stats.total_drops,
+                tzRuns: stats.tz.count,
+                runPerDay: stats.runs_per_day.toFixed(1)
+            };
+        }
         const runs = historyRecords.value;
         if (runs.length === 0)
             return {
@@ -161,17 +187,15 @@ export function useStats(
 
     const dropHistory = computed<DropHistoryEntry[]>(() => {
         const list: DropHistoryEntry[] = [];
-        const sorted = [...historyRecords.value].sort((a, b) => a.timestamp - b.timestamp);
-        sorted.forEach((run, idx) => {
+        historyRecords.value.forEach((run, idx) => {
             if (run.drops && run.drops.length) {
-                const scene = SCENES.find((s) => s.name === run.scene_id);
-                const sceneDisplay = scene ? getSceneName(scene) : run.scene_id || '?';
+                const sceneDisplay = run.scene_id ? getSceneName(run.scene_id) : '?';
 
                 run.drops.forEach((id) => {
                     const item = getItem(id);
                     if (item) {
                         list.push({
-                            runIdx: idx + 1,
+                            runIdx: totalRuns.value - idx,
                             itemName: currentLang.value === 'CN' ? item.name_zh : item.name,
                             itemNameZh: item.name_zh,
                             color: item.color,
@@ -182,7 +206,7 @@ export function useStats(
                 });
             }
         });
-        return list.reverse();
+        return list;
     });
 
     const collectedItemsSet = computed(() => {
@@ -196,8 +220,8 @@ export function useStats(
     });
 
     const grailStats = computed<GrailStats>(() => {
-        const total = ITEMS.length;
-        const collected = collectedItemsSet.value.size;
+        const total = catalog.value.length;
+        const collected = historyStats.value?.distinct_drops ?? collectedItemsSet.value.size;
         return {
             total,
             collected,
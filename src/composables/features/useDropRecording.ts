@@ -1,14 +1,25 @@
 /**
  * Drop recording functionality composable
  *
- * Handles item lookup and drop tracking during runs.
+ * Handles item lookup and drop tracking during runs. The item catalog is
+ * loaded once from the backend (`get_items`); custom items live in the
+ * backend's custom item table and are referenced by ID.
  */
 
-import { ref, type Ref } from 'vue';
-import type { ItemIndex } from '../../shared/types';
-import { ITEMS } from '../../shared/data';
+import { ref, computed, type Ref, type ComputedRef } from 'vue';
+import { invoke } from '@tauri-apps/api/core';
+import type { CustomItem, CustomItemInfo, CustomQuality, ItemIndex } from '../../shared/types';
 import { QUALITY_CONFIG } from '../../shared/constants';
 
+/** Prefix of legacy custom drop IDs (`custom:<name>:<quality>`), still found in cloud records */
+const LEGACY_CUSTOM_PREFIX = 'custom:';
+
+/** Prefix of custom item IDs */
+const CUSTOM_ITEM_PREFIX = 'custom-';
+
+/** Rarity class of each custom item quality */
+const QUALITY_RARITY_CLASS = { '1': 'normal', '2': 'magic', '3': 'rare' } as const;
+
 /** Session drop entry */
 export interface SessionDropEntry {
     itemId: string;
@@ -17,6 +28,24 @@ export interface SessionDropEntry {
 
 /** Drop recording composable return interface */
 export interface UseDropRecordingReturn {
+    /** Item catalog served by the backend */
+    catalog: Ref<ItemIndex[]>;
+    /** Load the item catalog from the backend */
+    loadCatalog: () => Promise<void>;
+    /** Custom items created by the user */
+    customItems: Ref<CustomItem[]>;
+    /** Catalog and custom items, in item index shape */
+    allItems: ComputedRef<ItemIndex[]>;
+    /** Load the custom items from the backend */
+    loadCustomItems: () => Promise<void>;
+    /** Create a custom item, or reuse one with the same name and quality */
+    createCustomItem: (name: string, quality: CustomQuality) => Promise<CustomItem>;
+    /** Change a custom item */
+    updateCustomItem: (id: string, info: CustomItemInfo) => Promise<CustomItem>;
+    /** Delete a custom item that is not used by any run */
+    deleteCustomItem: (id: string) => Promise<void>;
+    /** Merge a custom item into another, returning the number of runs changed */
+    mergeCustomItems: (sourceId: string, targetId: string) => Promise<number>;
     /** Drops recorded in current run */
     currentDrops: Ref<string[]>;
     /** All drops in current session with run numbers */
@@ -39,28 +68,120 @@ export interface UseDropRecordingReturn {
 export function useDropRecording(): UseDropRecordingReturn {
     const currentDrops = ref<string[]>([]);
     const sessionDrops = ref<SessionDropEntry[]>([]);
+    const catalog = ref<ItemIndex[]>([]);
+    const customItems = ref<CustomItem[]>([]);
+    const allItems = computed(() => [...catalog.value, ...customItems.value.map(toItemIndex)]);
+    const itemIndex = computed(() => new Map(allItems.value.map((i) => [i._id, i])));
+
+    /**
+     * Load the item catalog from the backend
+     */
+    async function loadCatalog(): Promise<void> {
+        try {
+            catalog.value = await invoke<ItemIndex[]>('get_items');
+        } catch (e) {
+            console.error('Failed to load item catalog', e);
+        }
+    }
+
+    /**
+     * Load the custom items from the backend
+     */
+    async function loadCustomItems(): Promise<void> {
+        try {
+            customItems.value = await invoke<CustomItem[]>('get_custom_items');
+        } catch (e) {
+            console.error('Failed to load custom items', e);
+        }
+    }
+
+    /**
+     * Create a custom item, or reuse one with the same name and quality
+     */
+    async function createCustomItem(name: string, quality: CustomQuality): Promise<CustomItem> {
+        const info: CustomItemInfo = { name_zh: name, name_en: name, quality };
+        const item = await invoke<CustomItem>('create_custom_item', { info });
+        if (!customItems.value.some((i) => i.id === item.id)) {
+            customItems.value.push(item);
+        }
+        return item;
+    }
+
+    /**
+     * Change a custom item
+     */
+    async function updateCustomItem(id: string, info: CustomItemInfo): Promise<CustomItem> {
+        const item = await invoke<CustomItem>('update_custom_item', { id, info });
+        await loadCustomItems();
+        return item;
+    }
+
+    /**
+     * Delete a custom item that is not used by any run
+     */
+    async function deleteCustomItem(id: string): Promise<void> {
+        await invoke('delete_custom_item', { id });
+        await loadCustomItems();
+    }
 
     /**
-     * Get item by ID, including support for custom items
+     * Merge a custom item into another
+     */
+    async function mergeCustomItems(sourceId: string, targetId: string): Promise<number> {
+        const changed = await invoke<number>('merge_custom_items', { sourceId, targetId });
+        await loadCustomItems();
+        return changed;
+    }
+
+    /**
+     * Present a custom item in item index shape
+     */
+    function toItemIndex(item: CustomItem): ItemIndex {
+        return {
+            _id: item.id,
+            name: item.name_en,
+            name_zh: item.name_zh,
+            rarity: item.quality,
+            color: QUALITY_CONFIG[item.quality]?.color || '#e4e4e7',
+            rarity_class: QUALITY_RARITY_CLASS[item.quality]
+        };
+    }
+
+    /**
+     * Get item by ID, including custom items and legacy custom drop IDs
+     *
+     * A custom item created on another install whose definition was not
+     * synced resolves to a generic normal-quality entry.
      */
     function getItem(id: string): ItemIndex | undefined {
-        // Check custom items
-        if (id.startsWith('custom:')) {
+        if (id.startsWith(LEGACY_CUSTOM_PREFIX)) {
             const parts = id.split(':');
-            const name = parts[1];
-            const quality = (parts[2] || '1') as '1' | '2' | '3';
-            const qConf = QUALITY_CONFIG[quality];
+            const last = parts[parts.length - 1];
+            const hasQuality = parts.length > 2 && last in QUALITY_CONFIG;
+            const quality = (hasQuality ? last : '1') as CustomQuality;
+            const name = parts.slice(1, hasQuality ? -1 : undefined).join(':');
             return {
                 _id: id,
                 name,
                 name_zh: name,
                 rarity: quality,
-                color: qConf?.color || '#e4e4e7'
+                color: QUALITY_CONFIG[quality].color,
+                rarity_class: QUALITY_RARITY_CLASS[quality]
             };
         }
 
-        // For database items, return as-is
-        return ITEMS.find((i) => i._id === id);
+        const item = itemIndex.value.get(id);
+        if (!item && id.startsWith(CUSTOM_ITEM_PREFIX)) {
+            return {
+                _id: id,
+                name: 'Custom Item',
+                name_zh: '自定义物品',
+                rarity: '1',
+                color: QUALITY_CONFIG['1'].color,
+                rarity_class: QUALITY_RARITY_CLASS['1']
+            };
+        }
+        return item;
     }
 
     /**
@@ -95,6 +216,15 @@ export function useDropRecording(): UseDropRecordingReturn {
     }
 
     return {
+        catalog,
+        loadCatalog,
+        customItems,
+        allItems,
+        loadCustomItems,
+        createCustomItem,
+        updateCustomItem,
+        deleteCustomItem,
+        mergeCustomItems,
         currentDrops,
         sessionDrops,
         getItem,
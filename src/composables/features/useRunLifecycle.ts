@@ -7,7 +7,12 @@
  */
 
 import { ref, computed, watch, type ComputedRef } from 'vue';
-import { ACTION_THROTTLE_MS } from '../../shared/constants';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { ACTION_THROTTLE_MS, MIN_RUN_DURATION_MS, SESSION_SAVE_THRESHOLD_MS } from '../../shared/constants';
+import type { RunCheckpoint } from '../../shared/types';
+import { describeError } from '../../shared/utils';
+import { useToast } from '../core/useToast';
 import type { UseTimerReturn } from '../core/useTimer';
 import type { UseSessionReturn } from './useSession';
 import type { UseDropRecordingReturn } from './useDropRecording';
@@ -22,15 +27,21 @@ export interface UseRunLifecycleReturn {
     /** Whether the run is effectively paused (user paused or search open) */
     isEffectivePaused: ComputedRef<boolean>;
     /** Start a new run immediately */
-    startNewRun: () => void;
+    startNewRun: () => Promise<void>;
     /** Finish current run and start next one */
     nextRun: () => Promise<void>;
     /** Finish current session and return to home */
     finishSession: () => Promise<void>;
     /** Toggle user pause state */
     togglePause: () => void;
+    /** Mirror a pause toggled by the backend for the pause shortcut */
+    onPauseShortcut: () => void;
+    /** Mark the end of the current segment */
+    splitRun: () => Promise<void>;
     /** Select a scene and prepare for run */
-    selectScene: (sceneName: string) => Promise<void>;
+    selectScene: (sceneId: string) => Promise<void>;
+    /** Offer to resume a run left unfinished by the last app exit */
+    checkPendingRun: (confirmResume: (checkpoint: RunCheckpoint) => boolean) => Promise<void>;
 }
 
 /**
@@ -44,11 +55,17 @@ export function useRunLifecycle(
     history: UseHistoryReturn,
     search: UseSearchReturn,
     navigation: UseNavigationReturn,
-    windowMgr: UseWindowReturn
+    windowMgr: UseWindowReturn,
+    t: (key: string) => string
 ): UseRunLifecycleReturn {
 
     const lastActionTimestamp = ref(0);
 
+    // The timer keeps running when its checkpoint cannot be written
+    listen<string>('checkpoint-error', (event) => {
+        useToast().error(`${t('CHECKPOINT_FAILED')} - ${event.payload}`, 5000);
+    });
+
     // Computed: effective pause state
     const isEffectivePaused = computed(() => {
         return timer.isUserPaused.value || search.isSearchOpen.value;
@@ -62,63 +79,64 @@ export function useRunLifecycle(
         return true;
     }
 
-    // Pause timing watcher
-    watch(isEffectivePaused, (newVal, oldVal) => {
-        if (!timer.isRunning.value) return;
-        if (newVal && !oldVal) {
-            timer.pauseStartTime.value = Date.now();
-        } else if (!newVal && oldVal && timer.pauseStartTime.value > 0) {
-            timer.totalPausedTime.value += Date.now() - timer.pauseStartTime.value;
-        }
+    // Forward pause state to the backend timer, which records the intervals
+    watch(isEffectivePaused, (paused) => {
+        timer.setPaused(paused).catch(console.error);
+    });
+
+    // Drops and TZ changes are checkpointed by the backend right away
+    watch(() => [...drops.currentDrops.value], (list) => {
+        timer.setDrops(list).catch(console.error);
+    });
+    watch(scenario.isTerrorZone, (isTz) => {
+        timer.setTz(isTz).catch(console.error);
     });
 
     /**
      * Start a new run
      */
-    function startNewRun() {
-        timer.stop();
-        timer.isUserPaused.value = false;
+    async function startNewRun() {
         search.closeSearch();
         drops.resetCurrentDrops();
+        if (!scenario.currentScene.value) return;
 
-        timer.start();
-        // Pass function to check paused state
-        timer.startTicker(() => isEffectivePaused.value);
+        try {
+            await timer.start(
+                scenario.currentScene.value.id,
+                scenario.isTerrorZone.value,
+                session.currentSessionId.value
+            );
+        } catch (e) {
+            console.error('Failed to start run', e);
+        }
     }
 
     /**
-     * Finish current run and start next one
+     * Stop the timer and save the run if it lasted at least `minDurationMs`
      */
-    async function nextRun() {
-        if (!scenario.currentScene.value || !timer.isRunning.value) return;
-        if (!checkThrottle()) return;
-
-        const duration = timer.elapsedTime.value;
-
-        // If run is too short, just restart without saving
-        if (!session.isValidRunDuration(duration)) {
-            startNewRun();
-            return;
-        }
-
-        session.updateSessionStats(duration);
-        session.incrementDailyRunCount();
-
-        const record = session.createRunRecord(
-            scenario.currentScene.value.name,
-            duration,
-            drops.currentDrops.value,
-            scenario.isTerrorZone.value
-        );
-
+    async function saveCurrentRun(minDurationMs: number) {
         try {
-            await session.saveRun(record);
+            const record = await timer.finish(minDurationMs);
+            if (!record) return;
+            session.updateSessionStats(record.duration_ms);
+            session.incrementDailyRunCount();
             await history.loadHistory();
         } catch (e) {
             console.error(e);
+            useToast().error(`${t('RUN_NOT_SAVED')} - ${describeError(e)}`, 5000);
         }
+    }
+
+    /**
+     * Finish current run and start next one
+     */
+    async function nextRun() {
+        if (!scenario.currentScene.value || !timer.isRunning.value) return;
+        if (!checkThrottle()) return;
 
-        startNewRun();
+        // Runs that are too short are discarded by the backend
+        await saveCurrentRun(MIN_RUN_DURATION_MS);
+        await startNewRun();
     }
 
     /**
@@ -128,22 +146,11 @@ export function useRunLifecycle(
         if (!checkThrottle()) return;
 
         // Save current run if it's substantial
-        if (timer.isRunning.value && session.meetsSessionSaveThreshold(timer.elapsedTime.value)) {
-            const record = session.createRunRecord(
-                scenario.currentScene.value?.name || '',
-                timer.elapsedTime.value,
-                drops.currentDrops.value,
-                scenario.isTerrorZone.value
-            );
-            try {
-                await session.saveRun(record);
-                await history.loadHistory();
-            } catch (e) {
-                console.error(e);
-            }
+        if (timer.isRunning.value) {
+            await saveCurrentRun(SESSION_SAVE_THRESHOLD_MS);
         }
 
-        timer.stop();
+        await session.endSession();
         navigation.goHome();
 
         // Resize window for Home view
@@ -177,11 +184,36 @@ export function useRunLifecycle(
         }
     }
 
+    /**
+     * Mirror a pause toggled by the backend for the pause shortcut
+     *
+     * Other pause sources (an open search) stay in force, so the backend
+     * timer is set back to the effective state.
+     */
+    function onPauseShortcut() {
+        if (!timer.isRunning.value) return;
+        timer.isUserPaused.value = !timer.isUserPaused.value;
+        timer.setPaused(isEffectivePaused.value).catch(console.error);
+    }
+
+    /**
+     * Mark the end of the current segment
+     */
+    async function splitRun() {
+        if (!timer.isRunning.value || isEffectivePaused.value) return;
+        if (!checkThrottle()) return;
+        try {
+            await timer.split();
+        } catch (e) {
+            console.error('Failed to split run', e);
+        }
+    }
+
     /**
      * Select a scene and prepare for run
      */
-    async function selectScene(sceneName: string) {
-        const scene = scenario.selectSceneByName(sceneName);
+    async function selectScene(sceneId: string) {
+        const scene = scenario.selectSceneById(sceneId);
         if (!scene) return;
 
         navigation.goTimer();
@@ -189,9 +221,49 @@ export function useRunLifecycle(
 
         session.resetSessionStats();
         drops.resetSessionDrops();
+        await session.startSession(scene.id, scenario.isTerrorZone.value);
+
+        await session.loadDailyRunCount(scene.id);
+        await startNewRun();
+    }
 
-        await session.loadDailyRunCount(scene.name);
-        startNewRun();
+    /**
+     * Offer to resume a run left unfinished by the last app exit
+     */
+    async function checkPendingRun(confirmResume: (checkpoint: RunCheckpoint) => boolean) {
+        let pending: RunCheckpoint | null = null;
+        try {
+            pending = await invoke<RunCheckpoint | null>('get_pending_run');
+        } catch (e) {
+            console.error('Failed to read pending run', e);
+        }
+        if (!pending) return;
+
+        const scene = scenario.selectSceneById(pending.scene_id);
+        if (!scene || !confirmResume(pending)) {
+            scenario.resetScenario();
+            await invoke('discard_pending_run').catch(console.error);
+            return;
+        }
+
+        scenario.isTerrorZone.value = pending.is_tz;
+        navigation.goTimer();
+        windowMgr.resizeForView('TIMER');
+
+        session.resetSessionStats();
+        drops.resetSessionDrops();
+        session.currentSessionId.value = pending.session_id;
+        await session.loadDailyRunCount(scene.id);
+
+        search.closeSearch();
+        drops.currentDrops.value = [...pending.drops];
+        // Time between the last checkpoint and the crash is not counted
+        try {
+            await timer.restorePending();
+        } catch (e) {
+            console.error('Failed to resume run', e);
+            await startNewRun();
+        }
     }
 
     return {
@@ -200,6 +272,9 @@ export function useRunLifecycle(
         nextRun,
         finishSession,
         togglePause,
-        selectScene
+        onPauseShortcut,
+        splitRun,
+        selectScene,
+        checkPendingRun
     };
 }
@@ -1,27 +1,41 @@
 /**
  * Scenario/Scene management composable
  *
- * Handles scene selection, terror zone toggle, and scene localization.
+ * Handles the scene registry, scene selection, terror zone toggle, and
+ * scene localization.
  */
 
 import { ref, type Ref } from 'vue';
-import type { Scene } from '../../shared/types';
-import { SCENES } from '../../shared/data';
+import { invoke } from '@tauri-apps/api/core';
+import type { Scene, SceneInfo } from '../../shared/types';
+
+/** ID of the built-in Terror Zone scene */
+const TERROR_ZONE_SCENE_ID = 'terror_zone';
 
 /** Scenario composable return interface */
 export interface UseScenarioReturn {
+    /** All scenes: built-in ones first, then custom ones */
+    scenes: Ref<Scene[]>;
     /** Currently selected scene */
     currentScene: Ref<Scene | null>;
     /** Whether terror zone is active */
     isTerrorZone: Ref<boolean>;
-    /** Get scene by name */
-    getScene: (sceneName: string) => Scene | undefined;
+    /** Load the scene registry from the backend */
+    loadScenes: () => Promise<void>;
+    /** Create a custom scene */
+    createScene: (info: SceneInfo) => Promise<Scene>;
+    /** Change a custom scene */
+    updateScene: (id: string, info: SceneInfo) => Promise<Scene>;
+    /** Delete a custom scene that has no runs */
+    deleteScene: (id: string) => Promise<void>;
+    /** Get scene by ID */
+    getScene: (sceneId: string) => Scene | undefined;
     /** Get localized scene name */
     getSceneName: (scene: Scene | string, lang: 'CN' | 'EN') => string;
     /** Get localized scene label */
     getSceneLabel: (scene: Scene | string, lang: 'CN' | 'EN') => string;
-    /** Select a scene by name */
-    selectSceneByName: (sceneName: string) => Scene | undefined;
+    /** Select a scene by ID */
+    selectSceneById: (sceneId: string) => Scene | undefined;
     /** Toggle terror zone state */
     toggleTerrorZone: () => void;
     /** Reset scene state for new session */
@@ -32,42 +46,80 @@ export interface UseScenarioReturn {
  * Create scenario composable instance
  */
 export function useScenario(): UseScenarioReturn {
+    const scenes = ref<Scene[]>([]);
     const currentScene = ref<Scene | null>(null);
     const isTerrorZone = ref(false);
 
     /**
-     * Get scene by name
+     * Load the scene registry from the backend
+     */
+    async function loadScenes(): Promise<void> {
+        try {
+            scenes.value = await invoke<Scene[]>('get_scenes');
+        } catch (e) {
+            console.error('Failed to load scenes', e);
+        }
+    }
+
+    /**
+     * Create a custom scene
+     */
+    async function createScene(info: SceneInfo): Promise<Scene> {
+        const scene = await invoke<Scene>('create_scene', { info });
+        await loadScenes();
+        return scene;
+    }
+
+    /**
+     * Change a custom scene
+     */
+    async function updateScene(id: string, info: SceneInfo): Promise<Scene> {
+        const scene = await invoke<Scene>('update_scene', { id, info });
+        await loadScenes();
+        return scene;
+    }
+
+    /**
+     * Delete a custom scene that has no runs
+     */
+    async function deleteScene(id: string): Promise<void> {
+        await invoke('delete_scene', { id });
+        await loadScenes();
+    }
+
+    /**
+     * Get scene by ID
      */
-    function getScene(sceneName: string): Scene | undefined {
-        return SCENES.find(s => s.name === sceneName);
+    function getScene(sceneId: string): Scene | undefined {
+        return scenes.value.find(s => s.id === sceneId);
     }
 
     /**
      * Get localized scene name
      */
     function getSceneName(scene: Scene | string, lang: 'CN' | 'EN'): string {
-        const s = typeof scene === 'string' ? SCENES.find(x => x.name === scene) : scene;
+        const s = typeof scene === 'string' ? getScene(scene) : scene;
         if (!s) return typeof scene === 'string' ? scene : '';
-        return lang === 'CN' ? s.name : (s.name_en || s.name);
+        return lang === 'CN' ? s.name_zh : (s.name_en || s.name_zh);
     }
 
     /**
      * Get localized scene label
      */
     function getSceneLabel(scene: Scene | string, lang: 'CN' | 'EN'): string {
-        const s = typeof scene === 'string' ? SCENES.find(x => x.name === scene) : scene;
+        const s = typeof scene === 'string' ? getScene(scene) : scene;
         if (!s) return typeof scene === 'string' ? scene : '';
-        return lang === 'CN' ? s.label : (s.label_en || s.label);
+        return lang === 'CN' ? s.label_zh : (s.label_en || s.label_zh);
     }
 
     /**
-     * Select a scene by name and return it
+     * Select a scene by ID and return it
      */
-    function selectSceneByName(sceneName: string): Scene | undefined {
-        const scene = getScene(sceneName);
+    function selectSceneById(sceneId: string): Scene | undefined {
+        const scene = getScene(sceneId);
         if (scene) {
             currentScene.value = scene;
-            if (scene.name === '恐怖地带') {
+            if (scene.id === TERROR_ZONE_SCENE_ID) {
                 isTerrorZone.value = true;
             }
         }
@@ -89,12 +141,17 @@ export function useScenario(): UseScenarioReturn {
     }
 
     return {
+        scenes,
         currentScene,
         isTerrorZone,
+        loadScenes,
+        createScene,
+        updateScene,
+        deleteScene,
         getScene,
         getSceneName,
         getSceneLabel,
-        selectSceneByName,
+        selectSceneById,
         toggleTerrorZone,
         resetScenario
     };
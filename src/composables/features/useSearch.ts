@@ -1,13 +1,14 @@
 /**
  * Search functionality composable
  *
- * Handles item search for drop recording, including fuzzy matching
- * and search result navigation.
+ * Handles item search for drop recording and search result navigation.
+ * Matching and ranking (Chinese name, pinyin initials, English name) is
+ * done by the backend's `search_items` command.
  */
 
 import { ref, type Ref } from 'vue';
-import type { ItemIndex } from '../../shared/types';
-import { ITEMS } from '../../shared/data';
+import { invoke } from '@tauri-apps/api/core';
+import type { CustomQuality, ItemIndex } from '../../shared/types';
 import { SEARCH_RESULTS_LIMIT, SEARCH_QUERY_MAX_LENGTH } from '../../shared/constants';
 import { sanitizeInput } from '../../shared/utils';
 
@@ -22,13 +23,13 @@ export interface UseSearchReturn {
     /** Currently selected result index */
     searchIndex: Ref<number>;
     /** Selected quality for custom items */
-    selectedQuality: Ref<'1' | '2' | '3'>;
+    selectedQuality: Ref<CustomQuality>;
     /** Open search panel and reset state */
     openSearch: () => void;
     /** Close search panel */
     closeSearch: () => void;
     /** Perform search with query */
-    performSearch: (q: string) => void;
+    performSearch: (q: string) => Promise<void>;
     /** Navigate search results up/down */
     navigateSearch: (dir: 1 | -1) => void;
     /** Create custom item with name and quality */
@@ -36,8 +37,8 @@ export interface UseSearchReturn {
         name: string,
         quality: string | undefined,
         t: (key: string) => string,
-        onItemCreated: (itemId: string) => void
-    ) => void;
+        onCreate: (name: string, quality: CustomQuality) => Promise<void>
+    ) => Promise<void>;
     /** Confirm selected drop item */
     confirmDrop: (
         item: ItemIndex | undefined,
@@ -53,7 +54,9 @@ export function useSearch(): UseSearchReturn {
     const searchQuery = ref('');
     const searchResults = ref<ItemIndex[]>([]);
     const searchIndex = ref(0);
-    const selectedQuality = ref<'1' | '2' | '3'>('1');
+    const selectedQuality = ref<CustomQuality>('1');
+    /** Sequence number of the latest search, so stale responses are dropped */
+    let searchSeq = 0;
 
     function openSearch() {
         isSearchOpen.value = true;
@@ -66,19 +69,26 @@ export function useSearch(): UseSearchReturn {
         isSearchOpen.value = false;
     }
 
-    /** Search items by name with fuzzy matching */
-    function performSearch(q: string) {
+    /** Search items by Chinese name, pinyin initials or English name */
+    async function performSearch(q: string) {
         const safeQ = q.slice(0, SEARCH_QUERY_MAX_LENGTH);
         searchQuery.value = safeQ;
         searchIndex.value = 0;
-        if (!safeQ) {
+        const seq = ++searchSeq;
+        if (!safeQ.trim()) {
             searchResults.value = [];
             return;
         }
-        const lower = safeQ.toLowerCase();
-        searchResults.value = ITEMS.filter(
-            (i) => i.name.toLowerCase().includes(lower) || i.name_zh.includes(lower)
-        ).slice(0, SEARCH_RESULTS_LIMIT);
+        try {
+            const results = await invoke<ItemIndex[]>('search_items', {
+                query: safeQ,
+                limit: SEARCH_RESULTS_LIMIT
+            });
+            if (seq === searchSeq) searchResults.value = results;
+        } catch (e) {
+            console.error('Item search failed', e);
+            if (seq === searchSeq) searchResults.value = [];
+        }
     }
 
     /** Navigate search results */
@@ -91,20 +101,25 @@ export function useSearch(): UseSearchReturn {
     }
 
     /** Create custom item with name and quality */
-    function createCustomItem(
+    async function createCustomItem(
         name: string,
         quality: string | undefined,
         t: (key: string) => string,
-        onItemCreated: (itemId: string) => void
+        onCreate: (name: string, quality: CustomQuality) => Promise<void>
     ) {
         const safeName = sanitizeInput(name);
         if (!safeName) {
             alert(t('INPUT_INVALID'));
             return;
         }
-        const q = quality || selectedQuality.value;
-        const customId = `custom:${safeName}:${q}`;
-        onItemCreated(customId);
+        const q = (quality || selectedQuality.value) as CustomQuality;
+        try {
+            await onCreate(safeName, q);
+        } catch (e) {
+            console.error('Failed to create custom item', e);
+            alert(t('INPUT_INVALID'));
+            return;
+        }
         closeSearch();
     }
 
@@ -5,9 +5,8 @@
  */
 
 import { ref, type Ref } from 'vue';
-import type { RunRecord } from '../../shared/types';
+import type { RunPage, Session } from '../../shared/types';
 import { invoke } from '@tauri-apps/api/core';
-import { MIN_RUN_DURATION_MS, SESSION_SAVE_THRESHOLD_MS } from '../../shared/constants';
 
 /** Session composable return interface */
 export interface UseSessionReturn {
@@ -21,25 +20,18 @@ export interface UseSessionReturn {
     sessionRunCount: Ref<number>;
     /** Total time spent in current session */
     sessionTotalTime: Ref<number>;
+    /** ID of the persisted session runs are saved into */
+    currentSessionId: Ref<string | null>;
     /** Reset session stats for new scene */
     resetSessionStats: () => void;
+    /** Start a persisted session for a scene */
+    startSession: (sceneId: string, isTz: boolean) => Promise<void>;
+    /** End the current persisted session */
+    endSession: () => Promise<void>;
     /** Load daily run count for scene */
-    loadDailyRunCount: (sceneName: string) => Promise<void>;
+    loadDailyRunCount: (sceneId: string) => Promise<void>;
     /** Update session stats after completing a run */
     updateSessionStats: (duration: number) => void;
-    /** Check if duration is valid for saving */
-    isValidRunDuration: (duration: number) => boolean;
-    /** Check if duration meets session save threshold */
-    meetsSessionSaveThreshold: (duration: number) => boolean;
-    /** Create a run record */
-    createRunRecord: (
-        sceneId: string,
-        duration: number,
-        drops: string[],
-        isTz: boolean
-    ) => RunRecord;
-    /** Save a run record */
-    saveRun: (record: RunRecord) => Promise<void>;
     /** Increment daily run count */
     incrementDailyRunCount: () => void;
 }
@@ -53,6 +45,7 @@ export function useSession(): UseSessionReturn {
     const sessionAvg = ref(0);
     const sessionRunCount = ref(0);
     const sessionTotalTime = ref(0);
+    const currentSessionId = ref<string | null>(null);
 
     /**
      * Reset session stats for new scene
@@ -64,16 +57,45 @@ export function useSession(): UseSessionReturn {
         sessionTotalTime.value = 0;
     }
 
+    /**
+     * Start a persisted session for a scene
+     */
+    async function startSession(sceneId: string, isTz: boolean): Promise<void> {
+        try {
+            const session: Session = await invoke('start_session', { sceneId, isTz });
+            currentSessionId.value = session.id;
+        } catch (e) {
+            // Runs are still saved, just without a session
+            console.error('Failed to start session', e);
+            currentSessionId.value = null;
+        }
+    }
+
+    /**
+     * End the current persisted session
+     */
+    async function endSession(): Promise<void> {
+        const id = currentSessionId.value;
+        if (!id) return;
+        currentSessionId.value = null;
+        try {
+            await invoke('end_session', { id });
+        } catch (e) {
+            console.error('Failed to end session', e);
+        }
+    }
+
     /**
      * Load daily run count for a specific scene
      */
-    async function loadDailyRunCount(sceneName: string): Promise<void> {
+    async function loadDailyRunCount(sceneId: string): Promise<void> {
         try {
             const todayStr = new Date().toISOString().split('T')[0];
-            const runs: RunRecord[] = await invoke('get_runs', {
-                filter: { startStr: todayStr, endStr: todayStr, sceneId: sceneName }
+            const page: RunPage = await invoke('get_runs', {
+                filter: { startStr: todayStr, endStr: todayStr, sceneId },
+                query: { limit: 0 }
             });
-            dailyRunCount.value = runs.length + 1;
+            dailyRunCount.value = page.total + 1;
         } catch {
             dailyRunCount.value = 1;
         }
@@ -91,47 +113,6 @@ export function useSession(): UseSessionReturn {
         }
     }
 
-    /**
-     * Check if duration is valid for saving
-     */
-    function isValidRunDuration(duration: number): boolean {
-        return duration >= MIN_RUN_DURATION_MS;
-    }
-
-    /**
-     * Check if duration meets session save threshold
-     */
-    function meetsSessionSaveThreshold(duration: number): boolean {
-        return duration > SESSION_SAVE_THRESHOLD_MS;
-    }
-
-    /**
-     * Create a run record
-     */
-    function createRunRecord(
-        sceneId: string,
-        duration: number,
-        drops: string[],
-        isTz: boolean
-    ): RunRecord {
-        return {
-            id: crypto.randomUUID(),
-            timestamp: Date.now(),
-            date_str: new Date().toISOString().split('T')[0],
-            scene_id: sceneId,
-            duration_ms: duration,
-            drops: [...drops],
-            is_tz: isTz
-        };
-    }
-
-    /**
-     * Save a run record to database
-     */
-    async function saveRun(record: RunRecord): Promise<void> {
-        await invoke('save_run', { run: record });
-    }
-
     /**
      * Increment daily run count
      */
@@ -145,13 +126,12 @@ export function useSession(): UseSessionReturn {
         sessionAvg,
         sessionRunCount,
         sessionTotalTime,
+        currentSessionId,
         resetSessionStats,
+        startSession,
+        endSession,
         loadDailyRunCount,
         updateSessionStats,
-        isValidRunDuration,
-        meetsSessionSaveThreshold,
-        createRunRecord,
-        saveRun,
         incrementDailyRunCount
     };
 }
@@ -1,16 +0,0 @@
-import { Scene } from '../shared/types';
-
-export const SCENES: Scene[] = [
-  { name: '泰摩高地-地穴', label: '地穴' },
-  { name: '遗忘高塔', label: '女伯爵' },
-  { name: '地下墓穴', label: '安姐' },
-  { name: '哞哞农场', label: '牛场' },
-  { name: '古代通道', label: '古代水道' },
-  { name: '塔拉夏古墓', label: '塔拉夏' },
-  { name: '崔凡克', label: '3C' },
-  { name: '憎恨囚牢', label: '劳模' },
-  { name: '混沌避难所', label: '超市' },
-  { name: '尼拉塞克', label: 'KP' },
-  { name: '世界之石', label: 'KB' },
-  { name: '恐怖地带', label: 'TZ' }
-];
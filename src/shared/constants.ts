@@ -39,6 +39,14 @@ export const SEARCH_QUERY_MAX_LENGTH = 20;
 export const MIN_RUN_DURATION_MS = 100;
 export const SESSION_SAVE_THRESHOLD_MS = 1000;
 
+// Runs fetched per page in the history view
+export const HISTORY_PAGE_SIZE = 50;
+
+// Shortcut trigger timings (ms); the backend accepts 100-5000
+export const DEFAULT_SEQUENCE_MS = 500;
+export const DEFAULT_DOUBLE_TAP_MS = 300;
+export const DEFAULT_HOLD_MS = 600;
+
 // Default theme opacity
 export const DEFAULT_THEME_OPACITY = 95;
 
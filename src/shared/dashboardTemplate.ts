@@ -1105,7 +1105,7 @@ export const DASHBOARD_HTML = `
         }
         
         function resolveSceneName(id) {
-            return globalData.scenes.find(s => s.name === id)?.name || id;
+            return globalData.scenes.find(s => s.id === id)?.name_zh || id;
         }
 
         function getQualityCode(item) { 
@@ -19,6 +19,7 @@ export const I18N: { CN: LocaleDict; EN: LocaleDict } = {
         AVG: '平均',
         VALUABLE: '掉落记录',
         ALL_LOGS: '所有记录',
+        LOAD_MORE: '加载更多',
         SHORTCUTS: '快捷键设置',
         PRESS_KEY: '请按键...',
         RESET: '恢复默认',
@@ -33,7 +34,19 @@ export const I18N: { CN: LocaleDict; EN: LocaleDict } = {
         KEY_NEXT: '下一把',
         KEY_PAUSE: '暂停/继续',
         KEY_SEARCH: '搜索掉落',
+        KEY_SPLIT: '分段',
         KEY_FINISH: '结束统计',
+        SHORTCUT_DUPLICATE: '与其他快捷键重复:',
+        SHORTCUT_OVERLAP: '按下时也会触发:',
+        SHORTCUT_GAME_KEY: '与游戏按键冲突:',
+        SHORTCUT_MODIFIER_ONLY: '不能只绑定修饰键',
+        SHORTCUT_UNKNOWN_KEY: '无法识别的按键',
+        SHORTCUT_INVALID_TIMING: '时间需在',
+        SHORTCUT_WHEEL_HOLD: '滚轮不能长按',
+        TRIGGER_PRESS: '单击',
+        TRIGGER_DOUBLE_TAP: '双击',
+        TRIGGER_HOLD: '长按',
+        TRIGGER_SEQUENCE: '组合',
         INPUT_INVALID: '输入含有非法字符或过长',
         QUALITY_SELECT: '选择品质',
         QUALITY_1: '底材',
@@ -76,7 +89,11 @@ export const I18N: { CN: LocaleDict; EN: LocaleDict } = {
         FILTER_INFO: '筛选条件',
         GRAIL_COLLECTED: '已收集',
         GRAIL_TOTAL: '总计',
-        GRAIL_COMPLETION: '完成度'
+        GRAIL_COMPLETION: '完成度',
+        RESUME_RUN_PROMPT: '检测到上次未完成的刷图，是否继续？',
+        RUN_NOT_SAVED: '本场未保存',
+        CHECKPOINT_FAILED: '进度备份失败',
+        SHORTCUTS_WINDOW_ONLY: '全局快捷键不可用，快捷键仅在本窗口激活时有效'
     },
     EN: {
         START: 'START RUN',
@@ -95,6 +112,7 @@ export const I18N: { CN: LocaleDict; EN: LocaleDict } = {
         AVG: 'AVG',
         VALUABLE: 'Valuable Drops',
         ALL_LOGS: 'Run Logs',
+        LOAD_MORE: 'Load More',
         SHORTCUTS: 'Shortcuts',
         PRESS_KEY: 'Press Key...',
         RESET: 'Reset Defaults',
@@ -109,7 +127,19 @@ export const I18N: { CN: LocaleDict; EN: LocaleDict } = {
         KEY_NEXT: 'Next Run',
         KEY_PAUSE: 'Pause',
         KEY_SEARCH: 'Search Drop',
+        KEY_SPLIT: 'Split',
         KEY_FINISH: 'Finish Session',
+        SHORTCUT_DUPLICATE: 'Same key as',
+        SHORTCUT_OVERLAP: 'Pressing it also fires',
+        SHORTCUT_GAME_KEY: 'Game key:',
+        SHORTCUT_MODIFIER_ONLY: 'Modifier keys cannot be bound alone',
+        SHORTCUT_UNKNOWN_KEY: 'Unknown key',
+        SHORTCUT_INVALID_TIMING: 'Time must be',
+        SHORTCUT_WHEEL_HOLD: 'The wheel cannot be held',
+        TRIGGER_PRESS: 'Press',
+        TRIGGER_DOUBLE_TAP: 'Double tap',
+        TRIGGER_HOLD: 'Hold',
+        TRIGGER_SEQUENCE: 'Sequence',
         INPUT_INVALID: 'Invalid Input',
         QUALITY_SELECT: 'Select Quality',
         QUALITY_1: 'Normal',
@@ -152,6 +182,10 @@ export const I18N: { CN: LocaleDict; EN: LocaleDict } = {
         FILTER_INFO: 'FILTERS',
         GRAIL_COLLECTED: 'Collected',
         GRAIL_TOTAL: 'Total',
-        GRAIL_COMPLETION: 'Completion'
+        GRAIL_COMPLETION: 'Completion',
+        RESUME_RUN_PROMPT: 'An unfinished run was found. Resume it?',
+        RUN_NOT_SAVED: 'Run not saved',
+        CHECKPOINT_FAILED: 'Run progress not backed up',
+        SHORTCUTS_WINDOW_ONLY: 'Global shortcuts are unavailable; shortcuts only work while this window is focused'
     }
 };
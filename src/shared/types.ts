@@ -17,14 +17,59 @@ export interface KeyBinding {
   ctrl: boolean;
   /** Whether Shift key is required */
   shift: boolean;
-  /** Key name as returned by rdev */
+  /** Whether the Meta (Windows/Command) key is required */
+  meta?: boolean;
+  /** Key name as returned by rdev, or a mouse button ('MouseMiddle', 'MouseBack', 'MouseForward', 'WheelUp', 'WheelDown') */
   name: string;
+  /** How the key has to be pressed (default: a single press) */
+  trigger?: KeyTrigger;
 }
 
+/**
+ * How the key of a shortcut has to be pressed. Timings are in milliseconds.
+ */
+export type KeyTrigger =
+  | { kind: 'press' }
+  /** `first`, then the bound key */
+  | { kind: 'sequence'; first: string; within_ms: number }
+  | { kind: 'double_tap'; within_ms: number }
+  | { kind: 'hold'; hold_ms: number };
+
+export type KeyTriggerKind = KeyTrigger['kind'];
+
+/**
+ * Shortcut action emitted by the backend (`hotkey-action` event).
+ */
+export type HotkeyAction = 'NEXT_RUN' | 'TOGGLE_PAUSE' | 'OPEN_SEARCH' | 'SPLIT' | 'FINISH_SESSION';
+
+/**
+ * Problem with a shortcut binding, reported by `validate_shortcuts`.
+ * Errors are rejected by `save_config`; warnings are not.
+ */
+export type ShortcutDiagnostic = {
+  /** Action name, as in `AppConfig.shortcuts` */
+  action: string;
+  severity: 'error' | 'warning';
+} & (
+  | { kind: 'unknown_action' }
+  | { kind: 'unknown_key' }
+  | { kind: 'modifier_only' }
+  /** Another action has the same key combination */
+  | { kind: 'duplicate'; with: string }
+  /** Another action fires on the same key with a different trigger */
+  | { kind: 'overlap'; with: string }
+  /** The key also does something in Diablo II, e.g. "Inventory" */
+  | { kind: 'game_key'; usage: string }
+  | { kind: 'invalid_timing'; min_ms: number; max_ms: number }
+  | { kind: 'wheel_hold' }
+);
+
 /**
  * Application configuration stored persistently.
  */
 export interface AppConfig {
+  /** Schema version of the persisted config (stamped by the backend) */
+  schemaVersion?: number;
   /** UI language code ('CN' for Chinese, 'EN' for English) */
   language?: 'CN' | 'EN';
   /** Theme identifier */
@@ -44,10 +89,16 @@ export interface AppConfig {
     userInfo?: any;
   };
   // secure_sync_time removed in favor of encrypting lastSyncTime directly
+  /** Days a deleted run stays in the trash before it is purged (default 30) */
+  trashRetentionDays?: number;
   /** User-customized window sizes per view (overrides defaults) */
   customViewSizes?: {
     [viewName: string]: { w: number; h: number };
   };
+  /** Segment names per scene, overriding the built-in ones */
+  sceneSegments?: {
+    [sceneId: string]: string[];
+  };
 }
 
 /**
@@ -68,24 +119,240 @@ export interface RunRecord {
   drops: string[];
   /** Whether this was a Terror Zone run */
   is_tz: boolean;
+  /** Edits made after the run was saved, oldest first */
+  edits?: RunEdit[];
+  /** The session this run was recorded in */
+  session_id?: string;
+  /** Events recorded while the run was timed; absent for older runs */
+  timeline?: RunEvent[];
+  /** Durations of the run's named segments; absent if the run was not split */
+  segments?: SegmentTime[];
+}
+
+/** Duration of one named segment of a run */
+export interface SegmentTime {
+  name: string;
+  duration_ms: number;
 }
 
+/** What happened at a point of a run's timeline */
+export type RunEventKind =
+  | { kind: 'start' }
+  | { kind: 'pause' }
+  | { kind: 'resume' }
+  | { kind: 'drop'; item_id: string }
+  | { kind: 'split' }
+  | { kind: 'finish' };
+
+/** One entry of a run's timeline */
+export type RunEvent = RunEventKind & {
+  /** Unix timestamp (milliseconds) of the event */
+  at: number;
+  /** Pause-adjusted elapsed time of the run at the event (ms) */
+  offset_ms: number;
+};
+
 /**
- * Scene definition for Diablo II locations.
+ * A run's event timeline (`get_run_timeline` command).
  */
-export interface Scene {
-  /** Internal scene name identifier */
-  name: string;
-  /** Display label for the scene */
-  label: string;
+export interface RunTimeline {
+  run_id: string;
+  /** false if only start/finish could be derived (run saved without a timeline) */
+  recorded: boolean;
+  /** Total time spent paused (ms) */
+  paused_ms: number;
+  events: RunEvent[];
+}
+
+/**
+ * A pause within a run, as Unix timestamps (milliseconds).
+ */
+export interface PauseInterval {
+  start: number;
+  /** null while still paused */
+  end: number | null;
+}
+
+/**
+ * Snapshot of the run in progress (`get_pending_run`).
+ */
+export interface RunCheckpoint {
+  /** Schema version of the checkpoint */
+  schemaVersion: number;
+  /** ID the run is saved under once finished */
+  run_id: string | null;
+  scene_id: string;
+  is_tz: boolean;
+  session_id: string | null;
+  /** Unix timestamp (milliseconds) when the run started */
+  started_at: number;
+  pauses: PauseInterval[];
+  drops: string[];
+  /** Elapsed time (ms) at each split so far */
+  splits: number[];
+  /** Events recorded so far */
+  timeline: RunEvent[];
+  /** Pause-adjusted elapsed time when the checkpoint was taken */
+  elapsed_ms: number;
+  /** Unix timestamp (milliseconds) when the checkpoint was taken */
+  saved_at: number;
+}
+
+/** Phase of the backend run timer */
+export type TimerPhase = 'idle' | 'running' | 'paused';
+
+/**
+ * Backend run timer state (`timer-state` event and timer commands).
+ */
+export interface TimerSnapshot {
+  phase: TimerPhase;
+  scene_id: string | null;
+  is_tz: boolean;
+  session_id: string | null;
+  /** Unix timestamp (milliseconds) when the run started */
+  started_at: number | null;
+  /** Pause-adjusted elapsed time in milliseconds */
+  elapsed_ms: number;
+  pauses: PauseInterval[];
+  /** Elapsed time (ms) at each split */
+  splits: number[];
+  drops: string[];
+}
+
+/** Payload of the `timer-tick` event */
+export interface TimerTick {
+  elapsed_ms: number;
+}
+
+/**
+ * A farming session: consecutive runs of one scene.
+ */
+export interface Session {
+  /** Unique identifier for this session */
+  id: string;
+  /** Scene the session was started in */
+  scene_id: string;
+  /** Unix timestamp (milliseconds) when the session started */
+  started_at: number;
+  /** Unix timestamp (milliseconds) when the session ended; null while open */
+  ended_at: number | null;
+  /** Whether the session was started as a Terror Zone session */
+  is_tz: boolean;
+  /** Free-form notes */
+  notes: string;
+}
+
+/**
+ * A session with aggregate statistics (`get_sessions` command).
+ */
+export interface SessionSummary extends Session {
+  /** Duration statistics of the session's runs */
+  stats: DurationStats;
+  /** Number of drops recorded in the session */
+  drops: number;
+}
+
+/**
+ * A session with its runs (`get_session` command).
+ */
+export interface SessionDetail extends SessionSummary {
+  /** The session's runs, oldest first */
+  runs: RunRecord[];
+}
+
+/**
+ * A deleted run waiting in the trash (`get_trash` command).
+ */
+export interface TrashedRun extends RunRecord {
+  /** Unix timestamp (milliseconds) when the run was deleted */
+  deleted_at: number;
+}
+
+/**
+ * Partial update for a saved run (`update_run` command).
+ */
+export interface RunPatch {
+  /** Replacement drop list */
+  drops?: string[];
+  /** Replacement scene identifier */
+  scene_id?: string;
+  /** Replacement Terror Zone flag */
+  is_tz?: boolean;
+  /** Replacement duration in milliseconds */
+  duration_ms?: number;
+}
+
+/**
+ * One entry in a run's edit history.
+ */
+export interface RunEdit {
+  /** Unix timestamp (milliseconds) of the edit */
+  edited_at: number;
+  /** Values of the changed fields before the edit */
+  previous: RunPatch;
+}
+
+/**
+ * Editable part of a scene (`create_scene` / `update_scene`).
+ */
+export interface SceneInfo {
+  /** Chinese scene name */
+  name_zh: string;
   /** English scene name */
-  name_en?: string;
-  /** English display label */
-  label_en?: string;
+  name_en: string;
+  /** Short Chinese label shown on the scene card */
+  label_zh: string;
+  /** Short English label shown on the scene card */
+  label_en: string;
+  /** Act the scene is in (1-5) */
+  act?: number | null;
+  /** In-game area */
+  area?: string | null;
+  /** Segment names in split order */
+  segments?: string[];
+}
+
+/**
+ * Scene of the backend scene registry (`get_scenes` command).
+ */
+export interface Scene extends SceneInfo {
+  /** Stable identifier, stored as `RunRecord.scene_id` */
+  id: string;
+  /** Whether the scene was created by the user */
+  custom: boolean;
 }
 
+/** Quality code of a custom item: normal, magic or rare */
+export type CustomQuality = '1' | '2' | '3';
+
 /**
- * Item index entry for drop recording.
+ * Editable part of a custom item (`create_custom_item` / `update_custom_item`).
+ */
+export interface CustomItemInfo {
+  /** Chinese item name */
+  name_zh: string;
+  /** English item name */
+  name_en: string;
+  /** Item quality */
+  quality: CustomQuality;
+  /** Base item type, e.g. "Monarch" */
+  base_type?: string | null;
+  /** Free-form notes */
+  notes?: string;
+}
+
+/**
+ * User-defined item, referenced from `RunRecord.drops` by its ID.
+ */
+export interface CustomItem extends CustomItemInfo {
+  /** Stable identifier (`custom-<uuid>`) */
+  id: string;
+  /** Creation time (Unix milliseconds) */
+  created_at: number;
+}
+
+/**
+ * Item index entry for drop recording, as served by the backend catalog.
  */
 export interface ItemIndex {
   /** Unique item identifier */
@@ -98,6 +365,10 @@ export interface ItemIndex {
   rarity: string;
   /** Display color for the item */
   color: string;
+  /** Pinyin initials of the Chinese name, used for search */
+  pinyin?: string;
+  /** Typed rarity derived from the raw category and color */
+  rarity_class?: RarityClass;
 }
 
 /**
@@ -110,6 +381,159 @@ export interface HistoryFilter {
   endStr?: string;
   /** Scene ID filter ('all' for no filter) */
   sceneId?: string;
+  /** Match any of these scene IDs */
+  sceneIds?: string[];
+  /** Start time filter (inclusive, Unix ms) */
+  startTime?: number;
+  /** End time filter (exclusive, Unix ms) */
+  endTime?: number;
+  /** Terror Zone filter */
+  isTz?: boolean;
+  /** Minimum duration in ms (inclusive) */
+  minDurationMs?: number;
+  /** Maximum duration in ms (inclusive) */
+  maxDurationMs?: number;
+  /** Whether the run has any drop */
+  hasDrops?: boolean;
+  /** Only runs that dropped this item ID */
+  itemId?: string;
+  /** Only runs with a drop of this rarity class */
+  rarity?: RarityClass;
+  /** Only runs recorded in this session */
+  sessionId?: string;
+}
+
+/** Sorting and paging parameters for get_runs */
+export interface RunQuery {
+  /** Sort key (default: 'timestamp') */
+  sortBy?: 'timestamp' | 'duration' | 'scene' | 'drops';
+  /** Sort direction (default: 'desc') */
+  sortDir?: 'asc' | 'desc';
+  /** Number of matching runs to skip */
+  offset?: number;
+  /** Maximum number of runs to return (all if omitted) */
+  limit?: number;
+}
+
+/** One page of a get_runs query */
+export interface RunPage {
+  /** Runs on this page */
+  runs: RunRecord[];
+  /** Number of runs matching the filter across all pages */
+  total: number;
+}
+
+/** Duration statistics for a group of runs (ms; null when empty) */
+export interface DurationStats {
+  count: number;
+  total_ms: number;
+  mean_ms: number;
+  median_ms: number | null;
+  p90_ms: number | null;
+  best_ms: number | null;
+  worst_ms: number | null;
+}
+
+/** Per-scene statistics returned by get_stats */
+export interface SceneStats extends DurationStats {
+  scene_id: string;
+  drops: number;
+}
+
+/** Aggregate statistics returned by get_stats */
+export interface RunStats {
+  overall: DurationStats;
+  /** Most-run scene first */
+  scenes: SceneStats[];
+  tz: DurationStats;
+  non_tz: DurationStats;
+  total_drops: number;
+  /** Number of distinct items dropped */
+  distinct_drops: number;
+  drops_by_rarity: Partial<Record<RarityClass, number>>;
+  active_days: number;
+  runs_per_day: number;
+}
+
+/** Duration statistics of one named segment */
+export interface SegmentStats extends DurationStats {
+  name: string;
+}
+
+/** Per-segment statistics of one scene (get_segment_stats) */
+export interface SegmentReport {
+  scene_id: string;
+  /** Number of runs split into segments */
+  runs: number;
+  /** Defined segments first, then other segment names found in runs */
+  segments: SegmentStats[];
+  /** null until every segment has been completed once */
+  sum_of_best_ms: number | null;
+  best_run_ms: number | null;
+}
+
+/** Item or rarity class a drop rate is measured for */
+export type DropTarget =
+  | { kind: 'item'; value: string }
+  | { kind: 'rarity'; value: RarityClass };
+
+/** Runs of one scene, optionally restricted to TZ or non-TZ */
+export interface SceneGroup {
+  scene_id: string;
+  /** Omit to include both TZ and non-TZ runs */
+  is_tz?: boolean;
+}
+
+/** Drop rate of one target with a 95% Wilson interval on the per-run chance */
+export interface DropRate {
+  target: DropTarget;
+  drops: number;
+  runs_with_drop: number;
+  drops_per_run: number;
+  runs_per_drop: number | null;
+  ci_low: number;
+  ci_high: number;
+}
+
+/** Drop rates for one scene and TZ combination (get_drop_rates) */
+export interface DropRateGroup {
+  scene_id: string;
+  is_tz: boolean;
+  runs: number;
+  items: DropRate[];
+  rarities: DropRate[];
+}
+
+/** Two-scene drop-rate comparison (compare_drop_rates) */
+export interface DropRateComparison {
+  a_runs: number;
+  a: DropRate;
+  b_runs: number;
+  b: DropRate;
+  difference: number;
+  z: number;
+  p_value: number;
+  significant: boolean;
+}
+
+/** Rarity class of a dropped item */
+export type RarityClass = 'rune' | 'runeword' | 'unique' | 'set' | 'rare' | 'magic' | 'normal';
+
+/**
+ * Error returned by backend storage commands.
+ */
+export interface StorageError {
+  /** Error category */
+  kind:
+    | 'Io'
+    | 'Database'
+    | 'Corrupted'
+    | 'Serialization'
+    | 'UnsupportedVersion'
+    | 'NotFound'
+    | 'Validation';
+  /** Human-readable error details */
+  message: string;
 }
 
 /**
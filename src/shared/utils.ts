@@ -2,10 +2,11 @@
  * Utility functions for the D2Run application.
  *
  * This module contains reusable helper functions for color manipulation,
- * input validation, and time formatting.
+ * input validation, time formatting, and error display.
  */
 
 import { MAX_INPUT_LENGTH } from './constants';
+import type { StorageError } from './types';
 
 /**
  * Apply opacity to a color string (rgba, rgb, or hex format).
@@ -89,3 +90,20 @@ export function formatTime(ms: number): string {
     const dec = Math.floor((ms % 1000) / 100);
     return `${m}:${s}.${dec}`;
 }
+
+/**
+ * Format an error rejected by a backend command for display.
+ *
+ * @param err - The rejection value from `invoke`
+ * @returns A readable error message
+ *
+ * @example
+ * describeError({ kind: 'Io', message: 'No space left on device' }) // 'Io: No space left on device'
+ */
+export function describeError(err: unknown): string {
+    const storageError = err as StorageError;
+    if (storageError && typeof storageError === 'object' && storageError.kind) {
+        return `${storageError.kind}: ${storageError.message}`;
+    }
+    return String(err);
+}
@@ -51,9 +51,12 @@ export const useRunStore = defineStore('run', () => {
   // Stats needs History & Helpers
   const statsComposable = useStats(
     historyComposable.historyRecords,
+    historyComposable.historyTotal,
+    historyComposable.historyStats,
     i18n.currentLang,
     (s) => scenario.getSceneName(s, i18n.currentLang.value),
-    drops.getItem
+    drops.getItem,
+    drops.catalog
   );
 
   // 3. Define Helper Functions (Glues)
@@ -97,8 +100,9 @@ export const useRunStore = defineStore('run', () => {
   // 4. Initialize Lifecycle & Dashboard
 
   const dashboardComposable = useDashboard(
-    historyComposable.historyRecords,
-    historyComposable.loadHistory
+    historyComposable.fetchAllRecords,
+    scenario.scenes,
+    drops.allItems
   );
 
   // Create a wrapped Window Manager that uses tryResize instead of raw resizeForView
@@ -115,7 +119,8 @@ export const useRunStore = defineStore('run', () => {
     historyComposable,
     search,
     navigation,
-    wrappedWindowMgr
+    wrappedWindowMgr,
+    i18n.t
   );
 
   // 5. Navigation Helpers (combining nav + resize + stops)
@@ -149,15 +154,16 @@ export const useRunStore = defineStore('run', () => {
   }
 
   function goBack() {
-    navigation.goBack(timer.stop);
+    navigation.goBack();
     if (navigation.view.value === 'HOME') tryResize('HOME');
   }
 
   // Search Helpers
-  function createCustomItem(name: string, quality?: string) {
-    search.createCustomItem(name, quality, i18n.t, (itemId) => {
-      drops.currentDrops.value.push(itemId);
-      drops.sessionDrops.value.unshift({ itemId, runNumber: session.dailyRunCount.value });
+  async function createCustomItem(name: string, quality?: string) {
+    await search.createCustomItem(name, quality, i18n.t, async (safeName, q) => {
+      const item = await drops.createCustomItem(safeName, q);
+      drops.currentDrops.value.push(item.id);
+      drops.sessionDrops.value.unshift({ itemId: item.id, runNumber: session.dailyRunCount.value });
     });
   }
 
@@ -189,8 +195,7 @@ export const useRunStore = defineStore('run', () => {
     isUserPaused: timer.isUserPaused,
     elapsedTime: timer.elapsedTime,
     startTime: timer.startTime,
-    totalPausedTime: timer.totalPausedTime,
-    pauseStartTime: timer.pauseStartTime,
+    splits: timer.splits,
 
     // Lifecycle
     isEffectivePaused: lifecycle.isEffectivePaused,
@@ -198,9 +203,28 @@ export const useRunStore = defineStore('run', () => {
     nextRun: lifecycle.nextRun,
     finishSession: lifecycle.finishSession,
     togglePause: lifecycle.togglePause,
+    onPauseShortcut: lifecycle.onPauseShortcut,
+    splitRun: lifecycle.splitRun,
     selectScene: lifecycle.selectScene,
+    checkPendingRun: () => lifecycle.checkPendingRun(() => window.confirm(i18n.t('RESUME_RUN_PROMPT'))),
     toggleTerrorZone: scenario.toggleTerrorZone,
 
+    // Scenes
+    scenes: scenario.scenes,
+    loadScenes: scenario.loadScenes,
+    createScene: scenario.createScene,
+    updateScene: scenario.updateScene,
+    deleteScene: scenario.deleteScene,
+
+    // Items
+    itemCatalog: drops.catalog,
+    loadCatalog: drops.loadCatalog,
+    customItems: drops.customItems,
+    loadCustomItems: drops.loadCustomItems,
+    updateCustomItem: drops.updateCustomItem,
+    deleteCustomItem: drops.deleteCustomItem,
+    mergeCustomItems: drops.mergeCustomItems,
+
     // Data State
     currentScene: scenario.currentScene,
     isTerrorZone: scenario.isTerrorZone,
@@ -214,6 +238,8 @@ export const useRunStore = defineStore('run', () => {
     historyFilter: historyComposable.historyFilter,
     historyRecords: historyComposable.historyRecords,
     loadHistory: historyComposable.loadHistory,
+    hasMoreHistory: historyComposable.hasMoreHistory,
+    loadMoreHistory: historyComposable.loadMoreHistory,
     deleteRun: historyComposable.deleteRun,
     totalRuns: statsComposable.totalRuns,
     sceneStats: statsComposable.sceneStats,
@@ -240,10 +266,12 @@ export const useRunStore = defineStore('run', () => {
     // Config & Settings
     config: configComposable.config,
     recordingKey: configComposable.recordingKey,
+    shortcutDiagnostics: configComposable.shortcutDiagnostics,
     loadConfig,
     resetSettings,
     startRecording: configComposable.startRecording,
     applyRecordedKey: configComposable.applyRecordedKey,
+    setShortcutTrigger: configComposable.setShortcutTrigger,
     cancelRecording: configComposable.cancelRecording,
 
     // Theme